@@ -0,0 +1,74 @@
+//! Segmentation throughput on the `data/good.*` files, with each backend.
+//!
+//! Run with `cargo bench --bench segment`.
+
+extern crate corpus;
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use corpus::{get_script, segment_with, Backend};
+
+const SCRIPTS: &[&str] = &[
+    "bn", "gu", "hi", "km", "kn", "ml", "my", "or", "pa", "si", "ta", "te",
+];
+
+/// Each file is segmented for at least this long
+const MIN_TIME: Duration = Duration::from_millis(500);
+
+const BACKENDS: &[(&str, Backend)] =
+    &[("combinators", Backend::Combinators), ("dfa", Backend::Dfa)];
+
+fn main() {
+    let data = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+    let mut total_bytes = vec![0; BACKENDS.len()];
+    let mut total_time = vec![Duration::new(0, 0); BACKENDS.len()];
+    let names: Vec<&str> = BACKENDS.iter().map(|&(name, _)| name).collect();
+    println!("\t\t\t\t\t{}", names.join("\t"));
+    for code in SCRIPTS {
+        let script = get_script(code).unwrap();
+        let text = fs::read_to_string(data.join(format!("good.{}", code))).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        let bytes: usize = lines.iter().map(|line| line.len()).sum();
+
+        let mut clusters = 0;
+        let mut speeds = Vec::new();
+        for (i, &(_, backend)) in BACKENDS.iter().enumerate() {
+            // the automaton is compiled on first use, which is not part of the timing; empty
+            // text would not use it
+            segment_with(lines[0], script, backend).count();
+            let mut rounds = 0;
+            clusters = 0;
+            let start = Instant::now();
+            while start.elapsed() < MIN_TIME {
+                for line in &lines {
+                    clusters += segment_with(line, script, backend).count();
+                }
+                rounds += 1;
+            }
+            let elapsed = start.elapsed();
+            clusters /= rounds;
+            total_bytes[i] += bytes * rounds;
+            total_time[i] += elapsed;
+            speeds.push(format!("{:.1} MB/s", mb_per_sec(bytes * rounds, elapsed)));
+        }
+        println!(
+            "good.{}\t{} lines\t{} clusters\t{}",
+            code,
+            lines.len(),
+            clusters,
+            speeds.join("\t")
+        );
+    }
+    let speeds: Vec<String> = total_bytes
+        .iter()
+        .zip(&total_time)
+        .map(|(&bytes, &time)| format!("{:.1} MB/s", mb_per_sec(bytes, time)))
+        .collect();
+    println!("total\t\t\t\t\t{}", speeds.join("\t"));
+}
+
+fn mb_per_sec(bytes: usize, elapsed: Duration) -> f64 {
+    bytes as f64 / 1e6 / elapsed.as_secs_f64()
+}
@@ -1,86 +1,195 @@
 use std::borrow::Cow;
 use std::char;
-use std::collections::HashSet;
 use std::env;
 use std::io;
-use std::io::BufRead;
-
-extern crate regex;
-
-use regex::{Captures, Regex};
-
-#[derive(Copy, Clone)]
-enum Script {
-    Devanagari,
-    Bengali,
-    Tamil,
-    Telugu,
-    Gujarati,
-    Gurmukhi,
-    Oriya,
-    Malayalam,
-    Myanmar,
-    Kannada,
-    Sinhala,
-}
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+extern crate bzip2;
+extern crate corpus;
+extern crate html_escape;
+extern crate quick_xml;
+extern crate serde_json;
+
+mod counter;
+mod html;
+mod wiki;
+
+use bzip2::read::MultiBzDecoder;
+use corpus::{detect_legacy, get_normalization, get_script, Legacy, Normalization, Script};
+use counter::{Counts, WordCounter};
+use html_escape::decode_html_entities;
+use serde_json::Value;
 
 enum Escape {
     None,
     Json,
     Html,
+    Wiki,
+    Reddit,
+}
+
+/// What to do with lines in a legacy encoding, such as Zawgyi or Krutidev, rather than
+/// Unicode.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum LegacyAction {
+    Keep,
+    /// Convert the lines there is a converter for, and skip the others
+    Convert,
+    Skip,
+}
+
+/// The legacy action, with the number of lines of each encoding that it was taken on by
+/// all of the threads.
+#[derive(Clone)]
+struct LegacyLines {
+    action: LegacyAction,
+    /// The only encoding that is looked for, as with `zawgyi=`
+    only: Option<Legacy>,
+    lines: Arc<[AtomicU64; Legacy::ALL.len()]>,
 }
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    if args.len() != 3 {
-        println!("Usage: corpus SCRIPT [none|json|html]");
+    if args.len() < 3 || args.len() > 8 {
+        println!("Usage: corpus SCRIPT [none|json|html|wiki|reddit] [count] [as-is|nfc|nfd]");
+        println!("                     [threads=N] [max-words=N] [legacy=convert|skip]");
         return;
     }
+    let mut count = false;
+    let mut norm = Normalization::AsIs;
+    let mut threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut max_words = 0;
+    let mut legacy_action = LegacyAction::Keep;
+    let mut only = None;
+    for arg in &args[3..] {
+        if arg == "count" {
+            count = true;
+        } else if let Some(n) = get_normalization(arg) {
+            norm = n;
+        } else if let Some(n) = arg.strip_prefix("threads=").and_then(|n| n.parse().ok()) {
+            threads = n;
+        } else if let Some(n) = arg.strip_prefix("max-words=").and_then(|n| n.parse().ok()) {
+            max_words = n;
+        } else if let Some(action) = arg.strip_prefix("legacy=").and_then(get_legacy_action) {
+            legacy_action = action;
+            only = None;
+        } else if let Some(action) = arg.strip_prefix("zawgyi=").and_then(get_legacy_action) {
+            legacy_action = action;
+            only = Some(Legacy::Zawgyi);
+        } else {
+            println!("unknown option");
+            return;
+        }
+    }
     if let Some(script) = get_script(&args[1]) {
+        if only.is_some_and(|legacy| !legacy.applies_to(script)) {
+            println!("zawgyi= only applies to my");
+            return;
+        }
+        let legacy = LegacyLines {
+            action: legacy_action,
+            only,
+            lines: Arc::new(Default::default()),
+        };
+        let legacy_lines = legacy.lines.clone();
         if let Some(escape) = get_escape(&args[2]) {
             let stdin = io::stdin();
-            let mut set = HashSet::new();
-            let json_re = Regex::new(r"[^\\]\\u([0-9a-fA-F]{4})").unwrap();
-            let html_re = Regex::new(r"&#([0-9]*);|&zwj;|&zwnj;").unwrap();
-            for res in stdin.lock().lines() {
-                let line = res.unwrap_or(String::from("")); // ignore invalid UTF-8
-                let line = match escape {
-                    Escape::None => Cow::from(line),
-                    Escape::Json => {
-                        json_re.replace_all(&line, |caps: &Captures| {
-                            let ds: Vec<u32> =
-                                caps[1].chars().map(|c| c.to_digit(16).unwrap()).collect();
-                            let u = (ds[0] << 12) | (ds[1] << 8) | (ds[2] << 4) | ds[3];
-                            let c = char::from_u32(u).unwrap_or(' '); // to catch surrogates
-                            c.to_string()
-                        })
+            // The input is read on this thread, and everything from extracting the text
+            // onwards is done by the counter's threads where possible
+            let words = match escape {
+                Escape::Wiki => {
+                    let mut articles = wiki::Articles::new(open_dump(stdin.lock()));
+                    // the namespaces come before the first page
+                    let first = articles.next();
+                    let namespaces = articles.namespaces().to_vec();
+                    let mut counter =
+                        WordCounter::new(threads, max_words, move |article, counts| {
+                            let text = wiki::strip_markup(article, &namespaces);
+                            add_words(script, norm, &legacy, &decode_html_entities(&text), counts);
+                        });
+                    for article in first.into_iter().chain(articles) {
+                        counter.add(article);
                     }
-                    Escape::Html => {
-                        html_re.replace_all(&line, |caps: &Captures| {
-                            match &caps[0] {
-                                "&zwj;" => '\u{200D}'.to_string(),
-                                "&zwnj;" => '\u{200C}'.to_string(),
-                                _ => {
-                                    let u = caps[1].parse::<u32>().unwrap();
-                                    let c = char::from_u32(u).unwrap_or(' '); // to catch surrogates
-                                    c.to_string()
+                    counter.finish()
+                }
+                Escape::Reddit => {
+                    let mut counter = WordCounter::new(threads, max_words, move |line, counts| {
+                        // skip lines that aren't valid JSON, such as truncated records
+                        if let Ok(Value::Object(record)) = serde_json::from_str(line) {
+                            for field in &["title", "body"] {
+                                if let Some(Value::String(s)) = record.get(*field) {
+                                    add_words(script, norm, &legacy, s, counts);
                                 }
                             }
-                        })
+                        }
+                    });
+                    for line in read_lines(&stdin) {
+                        counter.add(line);
                     }
-                };
-                for word in line
-                    .split(|c| !char_of_interest(script, c))
-                    .filter(|w| cool_word(script, w))
-                    .map(make_word)
-                {
-                    set.insert(word);
+                    counter.finish()
+                }
+                Escape::Html => {
+                    let mut counter = WordCounter::new(threads, max_words, move |text, counts| {
+                        add_words(script, norm, &legacy, text, counts);
+                    });
+                    let mut extractor = html::TextExtractor::new();
+                    for line in read_lines(&stdin) {
+                        let mut text = String::new();
+                        extractor.push_line(&line, &mut text);
+                        counter.add(text);
+                    }
+                    counter.finish()
+                }
+                Escape::None => {
+                    let mut counter = WordCounter::new(threads, max_words, move |line, counts| {
+                        add_words(script, norm, &legacy, line, counts);
+                    });
+                    for line in read_lines(&stdin) {
+                        counter.add(line);
+                    }
+                    counter.finish()
+                }
+                Escape::Json => {
+                    let mut counter = WordCounter::new(threads, max_words, move |line, counts| {
+                        add_words(script, norm, &legacy, &unescape_json(line), counts);
+                    });
+                    for line in read_lines(&stdin) {
+                        counter.add(line);
+                    }
+                    counter.finish()
+                }
+            };
+            let words = match words {
+                Ok(words) => words,
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    return;
                 }
+            };
+            if legacy_action != LegacyAction::Keep {
+                report_legacy(script, legacy_action, only, &legacy_lines);
             }
-            let mut words: Vec<String> = set.drain().collect();
-            words.sort();
+            let stdout = io::stdout();
+            let mut out = BufWriter::new(stdout.lock());
             for word in words {
-                println!("{}", word);
+                let (word, n) = match word {
+                    Ok(word) => word,
+                    Err(err) => {
+                        eprintln!("error: {}", err);
+                        return;
+                    }
+                };
+                let res = if count {
+                    writeln!(out, "{}\t{}", n, word)
+                } else {
+                    writeln!(out, "{}", word)
+                };
+                if res.is_err() {
+                    return;
+                }
             }
         } else {
             println!("unknown escape");
@@ -90,32 +199,166 @@ fn main() {
     }
 }
 
-fn get_script(s: &str) -> Option<Script> {
+fn get_legacy_action(s: &str) -> Option<LegacyAction> {
     match s {
-        "hi" => Some(Script::Devanagari),
-        "bn" => Some(Script::Bengali),
-        "ta" => Some(Script::Tamil),
-        "te" => Some(Script::Telugu),
-        "gu" => Some(Script::Gujarati),
-        "pa" => Some(Script::Gurmukhi),
-        "or" => Some(Script::Oriya),
-        "ml" => Some(Script::Malayalam),
-        "my" => Some(Script::Myanmar),
-        "kn" => Some(Script::Kannada),
-        "si" => Some(Script::Sinhala),
+        "convert" => Some(LegacyAction::Convert),
+        "skip" => Some(LegacyAction::Skip),
         _ => None,
     }
 }
 
+fn report_legacy(
+    script: Script,
+    action: LegacyAction,
+    only: Option<Legacy>,
+    lines: &[AtomicU64; Legacy::ALL.len()],
+) {
+    for legacy in Legacy::ALL {
+        let n = lines[legacy as usize].load(Ordering::Relaxed);
+        if !legacy.applies_to(script) || only.is_some_and(|only| only != legacy) {
+            continue;
+        }
+        let done = if action == LegacyAction::Convert && legacy.convertible() {
+            "converted"
+        } else {
+            "skipped"
+        };
+        eprintln!("{} lines in {} {}", n, legacy.name(), done);
+    }
+}
+
 fn get_escape(s: &str) -> Option<Escape> {
     match s {
         "none" => Some(Escape::None),
         "json" => Some(Escape::Json),
         "html" => Some(Escape::Html),
+        "wiki" => Some(Escape::Wiki),
+        "reddit" => Some(Escape::Reddit),
         _ => None,
     }
 }
 
+/// Reads a MediaWiki dump from `input`, decompressing it if it is bzip2.
+fn open_dump<'a, R: BufRead + 'a>(mut input: R) -> Box<dyn BufRead + 'a> {
+    let compressed = input.fill_buf().is_ok_and(|buf| buf.starts_with(b"BZh"));
+    if compressed {
+        Box::new(BufReader::new(MultiBzDecoder::new(input)))
+    } else {
+        Box::new(input)
+    }
+}
+
+/// Decodes the escapes of JSON strings in `s`, which may be a whole line of JSON.
+///
+/// Lone surrogates can't be represented in a Rust string and are replaced with U+FFFD.
+fn unescape_json<'a>(s: &'a str) -> Cow<'a, str> {
+    if !s.contains('\\') {
+        return Cow::from(s);
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(i) = rest.find('\\') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i + 1..];
+        let c = match rest.chars().next() {
+            Some(c) => c,
+            None => {
+                out.push('\\');
+                break;
+            }
+        };
+        rest = &rest[c.len_utf8()..];
+        match c {
+            'n' => out.push('\n'),
+            't' => out.push('\t'),
+            'r' => out.push('\r'),
+            'b' => out.push('\u{8}'),
+            'f' => out.push('\u{C}'),
+            'u' => match hex4(rest) {
+                Some(hi @ 0xD800..=0xDBFF) => {
+                    let lo = rest[4..].strip_prefix("\\u").and_then(hex4);
+                    match lo {
+                        Some(lo @ 0xDC00..=0xDFFF) => {
+                            let u = 0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00);
+                            out.push(char::from_u32(u).unwrap());
+                            rest = &rest[10..];
+                        }
+                        _ => {
+                            out.push(char::REPLACEMENT_CHARACTER);
+                            rest = &rest[4..];
+                        }
+                    }
+                }
+                Some(u) => {
+                    // a lone low surrogate
+                    out.push(char::from_u32(u).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    rest = &rest[4..];
+                }
+                None => out.push_str("\\u"),
+            },
+            // \" \\ and \/ stand for themselves
+            _ => out.push(c),
+        }
+    }
+    out.push_str(rest);
+    Cow::from(out)
+}
+
+/// Parses the four hex digits of a `\u` escape at the start of `s`.
+fn hex4(s: &str) -> Option<u32> {
+    let digits = s.get(..4)?;
+    if digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        u32::from_str_radix(digits, 16).ok()
+    } else {
+        None
+    }
+}
+
+fn read_lines(stdin: &io::Stdin) -> impl Iterator<Item = String> + '_ {
+    // ignore invalid UTF-8
+    stdin.lock().lines().map(|res| res.unwrap_or_default())
+}
+
+/// Counts the words of `text`, first converting or skipping any lines in a legacy encoding.
+fn add_words(
+    script: Script,
+    norm: Normalization,
+    legacy: &LegacyLines,
+    text: &str,
+    counts: &mut Counts,
+) {
+    if legacy.action == LegacyAction::Keep {
+        add_text_words(script, norm, text, counts);
+        return;
+    }
+    for line in text.lines() {
+        let found = match detect_legacy(line, script) {
+            Some(found) if legacy.only.is_none_or(|only| only == found) => found,
+            _ => {
+                add_text_words(script, norm, line, counts);
+                continue;
+            }
+        };
+        legacy.lines[found as usize].fetch_add(1, Ordering::Relaxed);
+        if legacy.action == LegacyAction::Convert {
+            if let Some(converted) = found.convert(line) {
+                add_text_words(script, norm, &converted, counts);
+            }
+        }
+    }
+}
+
+fn add_text_words(script: Script, norm: Normalization, text: &str, counts: &mut Counts) {
+    for word in norm
+        .normalize(text)
+        .split(|c| !char_of_interest(script, c))
+        .filter(|w| cool_word(script, w))
+        .map(make_word)
+    {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+}
+
 fn char_of_interest(script: Script, c: char) -> bool {
     indic_script_char(script, c) || latin_combining_char(c)
 }
@@ -163,7 +406,7 @@ fn indic_script_char(script: Script, c: char) -> bool {
                 || devanagari_anudatta_char(c)
                 || misc_char(c)
         }
-        Script::Myanmar => myanmar_char(c) || vedic_extensions_char(c) || myanmar_misc_char(c),
+        Script::Myanmar => myanmar_char(c) || vedic_extensions_char(c) || joiner_misc_char(c),
         Script::Kannada => {
             kannada_char(c)
                 || vedic_extensions_char(c)
@@ -171,12 +414,14 @@ fn indic_script_char(script: Script, c: char) -> bool {
                 || misc_char(c)
         }
         Script::Sinhala => sinhala_char(c) || vedic_extensions_char(c) || misc_char(c),
+        Script::Khmer => khmer_char(c) || joiner_misc_char(c),
+        _ => script_specific_char(script, c) || joiner_misc_char(c),
     }
 }
 
 fn latin_combining_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0x300 && cp <= 0x36F
+    (0x300..=0x36F).contains(&cp)
 }
 
 fn script_specific_char(script: Script, c: char) -> bool {
@@ -192,37 +437,45 @@ fn script_specific_char(script: Script, c: char) -> bool {
         Script::Myanmar => myanmar_char(c),
         Script::Kannada => kannada_char(c),
         Script::Sinhala => sinhala_char(c),
+        Script::Khmer => khmer_char(c),
+        Script::Balinese => balinese_char(c),
+        Script::Javanese => javanese_char(c),
+        Script::TaiTham => tai_tham_char(c),
+        Script::Chakma => chakma_char(c),
+        Script::Sharada => sharada_char(c),
+        Script::Tirhuta => tirhuta_char(c),
+        Script::Kawi => kawi_char(c),
     }
 }
 
 fn bengali_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0x980 && cp <= 0x9FF
+    (0x980..=0x9FF).contains(&cp)
 }
 
 fn devanagari_char(c: char) -> bool {
     let cp = c as u32;
-    (cp >= 0x900 && cp <= 0x97F) || (cp >= 0xA8E0 && cp <= 0xA8FF)
+    (0x900..=0x97F).contains(&cp) || (0xA8E0..=0xA8FF).contains(&cp)
 }
 
 fn gujarati_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0xA80 && cp <= 0xAFF
+    (0xA80..=0xAFF).contains(&cp)
 }
 
 fn gurmukhi_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0xA00 && cp <= 0xA7F
+    (0xA00..=0xA7F).contains(&cp)
 }
 
 fn kannada_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0xC80 && cp <= 0xCFF
+    (0xC80..=0xCFF).contains(&cp)
 }
 
 fn malayalam_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0xD00 && cp <= 0xD7F
+    (0xD00..=0xD7F).contains(&cp)
 }
 
 fn myanmar_char(c: char) -> bool {
@@ -234,7 +487,7 @@ fn myanmar_char(c: char) -> bool {
     )
 }
 
-fn myanmar_misc_char(c: char) -> bool {
+fn joiner_misc_char(c: char) -> bool {
     // match c {
     //     '\u{00A0}' => true, // No-break space
     //     '\u{200C}' => true, // Zero-width non-joiner
@@ -251,24 +504,58 @@ fn myanmar_misc_char(c: char) -> bool {
     c == '\u{200C}' || c == '\u{200D}' || c == '\u{25CC}' // zwnj, zwj, dotted circle
 }
 
+fn khmer_char(c: char) -> bool {
+    // U+1780..U+17FF Khmer block
+    // U+19E0..U+19FF Khmer Symbols
+    matches!(c, '\u{1780}'..='\u{17FF}' | '\u{19E0}'..='\u{19FF}')
+}
+
 fn oriya_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0xB00 && cp <= 0xB7F
+    (0xB00..=0xB7F).contains(&cp)
 }
 
 fn sinhala_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0xD70 && cp <= 0xDFF
+    (0xD70..=0xDFF).contains(&cp)
 }
 
 fn tamil_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0xB80 && cp <= 0xBFF
+    (0xB80..=0xBFF).contains(&cp)
 }
 
 fn telugu_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0xC00 && cp <= 0xC7F
+    (0xC00..=0xC7F).contains(&cp)
+}
+
+fn balinese_char(c: char) -> bool {
+    matches!(c, '\u{1B00}'..='\u{1B7F}')
+}
+
+fn javanese_char(c: char) -> bool {
+    matches!(c, '\u{A980}'..='\u{A9DF}')
+}
+
+fn tai_tham_char(c: char) -> bool {
+    matches!(c, '\u{1A20}'..='\u{1AAF}')
+}
+
+fn chakma_char(c: char) -> bool {
+    matches!(c, '\u{11100}'..='\u{1114F}')
+}
+
+fn sharada_char(c: char) -> bool {
+    matches!(c, '\u{11180}'..='\u{111DF}')
+}
+
+fn tirhuta_char(c: char) -> bool {
+    matches!(c, '\u{11480}'..='\u{114DF}')
+}
+
+fn kawi_char(c: char) -> bool {
+    matches!(c, '\u{11F00}'..='\u{11F5F}')
 }
 
 fn grantha_marks_char(c: char) -> bool {
@@ -278,7 +565,7 @@ fn grantha_marks_char(c: char) -> bool {
 
 fn vedic_extensions_char(c: char) -> bool {
     let cp = c as u32;
-    cp >= 0x1CD0 && cp <= 0x1CFF
+    (0x1CD0..=0x1CFF).contains(&cp)
 }
 
 fn devanagari_anudatta_char(c: char) -> bool {
@@ -327,3 +614,60 @@ fn non_empty(word: &&str) -> bool {
     !word.is_empty()
 }
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unescape_json_escapes() {
+        let cases = [
+            // adjacent escapes
+            (r"\u0915\u094d\u0937", "\u{915}\u{94D}\u{937}"),
+            // an escape at the start of the line
+            (r"\u0915 text", "\u{915} text"),
+            // a surrogate pair
+            (r"a\ud83d\ude00b", "a\u{1F600}b"),
+            // lone surrogates
+            (r"a\ud83db", "a\u{FFFD}b"),
+            (r"a\ude00b", "a\u{FFFD}b"),
+            (r"\ud83d\u0915", "\u{FFFD}\u{915}"),
+            // an escaped backslash before a u
+            (r"\\u0915", r"\u0915"),
+            (r"\\\u0915", "\\\u{915}"),
+            (r#"\"quoted\" \/ \n"#, "\"quoted\" / \n"),
+            // not an escape
+            (r"\u09", r"\u09"),
+            (r"trailing \\", r"trailing \"),
+        ];
+        for &(json, text) in &cases {
+            assert_eq!(unescape_json(json), text, "{}", json);
+        }
+    }
+
+    #[test]
+    fn zawgyi_skips_only_zawgyi() {
+        let line =
+            "\u{C0}\u{C1}\u{C2}\u{C3}\u{C4}\u{C5} \u{1019}\u{103C}\u{1014}\u{103A}\u{1019}\u{102C}";
+        assert_eq!(
+            detect_legacy(line, Script::Myanmar),
+            Some(Legacy::FontEncoding)
+        );
+        for &(only, words) in &[(None, 0), (Some(Legacy::Zawgyi), 1)] {
+            let legacy = LegacyLines {
+                action: LegacyAction::Skip,
+                only,
+                lines: Arc::new(Default::default()),
+            };
+            let mut counts = Counts::new();
+            add_words(
+                Script::Myanmar,
+                Normalization::AsIs,
+                &legacy,
+                line,
+                &mut counts,
+            );
+            assert_eq!(counts.len(), words, "{:?}", only);
+        }
+    }
+}
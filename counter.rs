@@ -0,0 +1,302 @@
+//! Counting words on several threads, spilling them to disk when there are too many.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+pub type Counts = HashMap<String, u64>;
+
+/// Size of the chunks of text handed to the threads
+const CHUNK_BYTES: usize = 1 << 20;
+
+/// Most runs that are read at once, to stay within the limit on open files
+const MAX_OPEN_RUNS: usize = 64;
+
+/// What each thread ends up with: the sorted runs it wrote to disk and the words it still
+/// has in memory, sorted.
+type Output = (Vec<PathBuf>, Vec<(String, u64)>);
+
+/// A word with its count, or the error from reading it from a run
+type Word = io::Result<(String, u64)>;
+
+/// Counts the words in texts with a function run on several threads.
+///
+/// Each thread has its own counts, which are merged at the end. With a limit on the number
+/// of words, a thread whose counts reach its share of the limit writes them to a temporary
+/// file as a sorted run and starts again, and the runs are merged at the end.
+pub struct WordCounter {
+    sender: SyncSender<Vec<String>>,
+    chunk: Vec<String>,
+    chunk_bytes: usize,
+    workers: Vec<JoinHandle<io::Result<Output>>>,
+}
+
+impl WordCounter {
+    /// `max_words` is the number of distinct words held in memory, or 0 for no limit.
+    pub fn new<F>(threads: usize, max_words: usize, add_words: F) -> WordCounter
+    where
+        F: Fn(&str, &mut Counts) + Clone + Send + 'static,
+    {
+        let threads = threads.max(1);
+        let (sender, receiver) = mpsc::sync_channel(threads * 2);
+        let receiver = Arc::new(Mutex::new(receiver));
+        let max_words = if max_words == 0 {
+            usize::MAX
+        } else {
+            (max_words / threads).max(1)
+        };
+        let workers = (0..threads)
+            .map(|id| {
+                let receiver = receiver.clone();
+                let add_words = add_words.clone();
+                thread::spawn(move || count_words(id, &receiver, max_words, add_words))
+            })
+            .collect();
+        WordCounter {
+            sender,
+            chunk: Vec::new(),
+            chunk_bytes: 0,
+            workers,
+        }
+    }
+
+    pub fn add(&mut self, text: String) {
+        self.chunk_bytes += text.len();
+        self.chunk.push(text);
+        if self.chunk_bytes >= CHUNK_BYTES {
+            self.send_chunk();
+        }
+    }
+
+    fn send_chunk(&mut self) {
+        self.chunk_bytes = 0;
+        let chunk = mem::take(&mut self.chunk);
+        // the threads only stop early if one of them failed, which `finish` reports
+        let _ = self.sender.send(chunk);
+    }
+
+    /// Waits for all the text to be counted and returns the words in sorted order.
+    pub fn finish(mut self) -> io::Result<Merge> {
+        self.send_chunk();
+        drop(self.sender);
+        let mut merge = Merge::new(Vec::new())?;
+        let mut result = Ok(());
+        for worker in self.workers {
+            match worker.join().expect("counting thread panicked") {
+                Ok((files, words)) => {
+                    merge.files.extend(files);
+                    merge.sources.push(Box::new(words.into_iter().map(Ok)));
+                }
+                Err(err) => result = Err(err),
+            }
+        }
+        // `merge` removes the runs written so far if there was an error
+        result?;
+        // merge the runs into fewer, longer runs until they can all be opened at once
+        let mut n = 0;
+        while merge.files.len() > 1 && merge.files.len() + merge.sources.len() > MAX_OPEN_RUNS {
+            let end = MAX_OPEN_RUNS.min(merge.files.len());
+            let files: Vec<PathBuf> = merge.files.drain(..end).collect();
+            let path = run_path("merged", n);
+            n += 1;
+            merge.files.push(path.clone());
+            let mut pass = Merge::new(files)?;
+            write_run(&path, &mut pass)?;
+        }
+        merge.start()?;
+        Ok(merge)
+    }
+}
+
+fn count_words<F>(
+    id: usize,
+    receiver: &Mutex<Receiver<Vec<String>>>,
+    max_words: usize,
+    add_words: F,
+) -> io::Result<Output>
+where
+    F: Fn(&str, &mut Counts),
+{
+    let mut counts = Counts::new();
+    let mut files = Vec::new();
+    loop {
+        let chunk = match receiver.lock().unwrap().recv() {
+            Ok(chunk) => chunk,
+            Err(_) => break,
+        };
+        for text in chunk {
+            add_words(&text, &mut counts);
+            if counts.len() >= max_words {
+                let path = run_path(&id.to_string(), files.len());
+                let words = sorted(mem::take(&mut counts));
+                files.push(path.clone());
+                if let Err(err) = write_run(&path, words.into_iter().map(Ok)) {
+                    for path in &files {
+                        let _ = fs::remove_file(path);
+                    }
+                    return Err(err);
+                }
+            }
+        }
+    }
+    Ok((files, sorted(counts)))
+}
+
+fn sorted(counts: Counts) -> Vec<(String, u64)> {
+    let mut words: Vec<(String, u64)> = counts.into_iter().collect();
+    words.sort();
+    words
+}
+
+fn run_path(name: &str, n: usize) -> PathBuf {
+    env::temp_dir().join(format!("corpus-{}-{}-{}.run", process::id(), name, n))
+}
+
+fn write_run(path: &Path, words: impl IntoIterator<Item = Word>) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for word in words {
+        let (word, n) = word?;
+        writeln!(writer, "{}\t{}", n, word)?;
+    }
+    writer.flush()
+}
+
+/// The words from all of the threads and runs, in sorted order with their total counts.
+///
+/// Reading stops at the first error from a run.
+pub struct Merge {
+    sources: Vec<Box<dyn Iterator<Item = Word>>>,
+    /// The next word of each source, with its count and the index of the source
+    heap: BinaryHeap<Reverse<(String, u64, usize)>>,
+    files: Vec<PathBuf>,
+}
+
+impl Merge {
+    /// A merge of the runs in `files`, which it removes when it is dropped.
+    fn new(files: Vec<PathBuf>) -> io::Result<Merge> {
+        let mut merge = Merge {
+            sources: Vec::new(),
+            heap: BinaryHeap::new(),
+            files,
+        };
+        if !merge.files.is_empty() {
+            merge.start()?;
+        }
+        Ok(merge)
+    }
+
+    /// Opens the runs and starts reading all of the sources.
+    fn start(&mut self) -> io::Result<()> {
+        for path in &self.files {
+            let reader = BufReader::new(File::open(path)?);
+            self.sources
+                .push(Box::new(reader.lines().map(|line| parse_run_line(&line?))));
+        }
+        for i in 0..self.sources.len() {
+            self.refill(i)?;
+        }
+        Ok(())
+    }
+
+    fn refill(&mut self, i: usize) -> io::Result<()> {
+        if let Some(word) = self.sources[i].next() {
+            let (word, n) = word?;
+            self.heap.push(Reverse((word, n, i)));
+        }
+        Ok(())
+    }
+
+    fn next_word(&mut self) -> io::Result<Option<(String, u64)>> {
+        let Reverse((word, mut total, i)) = match self.heap.pop() {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+        self.refill(i)?;
+        while let Some(Reverse((next, _, _))) = self.heap.peek() {
+            if *next != word {
+                break;
+            }
+            let Reverse((_, n, i)) = self.heap.pop().unwrap();
+            total += n;
+            self.refill(i)?;
+        }
+        Ok(Some((word, total)))
+    }
+}
+
+fn parse_run_line(line: &str) -> Word {
+    let malformed = || io::Error::new(io::ErrorKind::InvalidData, "malformed sorted run");
+    let (n, word) = line.split_once('\t').ok_or_else(malformed)?;
+    Ok((word.to_string(), n.parse().map_err(|_| malformed())?))
+}
+
+impl Iterator for Merge {
+    type Item = Word;
+
+    fn next(&mut self) -> Option<Word> {
+        match self.next_word() {
+            Ok(word) => word.map(Ok),
+            Err(err) => {
+                // nothing after an error can be trusted to be in order
+                self.heap.clear();
+                self.sources.clear();
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl Drop for Merge {
+    fn drop(&mut self) {
+        for path in &self.files {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add_words(text: &str, counts: &mut Counts) {
+        for word in text.split_whitespace() {
+            *counts.entry(word.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    #[test]
+    fn spilled_runs_are_merged() {
+        let mut counter = WordCounter::new(2, 2, add_words);
+        for text in &["b a", "c a", "d b", "a"] {
+            counter.add(text.to_string());
+        }
+        let words: Vec<_> = counter.finish().unwrap().map(Result::unwrap).collect();
+        let expected = [("a", 3), ("b", 2), ("c", 1), ("d", 1)];
+        let expected: Vec<_> = expected.iter().map(|&(w, n)| (w.to_string(), n)).collect();
+        assert_eq!(words, expected);
+    }
+
+    #[test]
+    fn bad_runs_are_errors() {
+        let missing = run_path("test-missing", 0);
+        assert!(Merge::new(vec![missing]).is_err());
+
+        let malformed = run_path("test-malformed", 0);
+        fs::write(&malformed, "1\ta\nb\n").unwrap();
+        let words: Vec<_> = Merge::new(vec![malformed.clone()]).unwrap().collect();
+        assert_eq!(words.len(), 1);
+        assert_eq!(
+            words[0].as_ref().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert!(!malformed.exists());
+    }
+}
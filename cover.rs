@@ -0,0 +1,132 @@
+//! Choosing a small set of words that together contain every syllable of a corpus.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::io::BufRead;
+
+use corpus::{pattern, segment_with, Backend, Normalization, Script};
+
+use super::split_count;
+
+/// Longest line of words printed
+const LINE_WIDTH: usize = 60;
+
+#[derive(Copy, Clone)]
+pub enum CoverBy {
+    Syllable,
+    Pattern,
+}
+
+struct Word {
+    text: String,
+    count: u64,
+    /// The syllables or patterns in the word, as indices into the list of all of them
+    keys: Vec<usize>,
+}
+
+/// Reads words from `input`, as written by `corpus` or separated by whitespace, and prints
+/// a small selection of them that contains every distinct syllable (or syllable pattern).
+///
+/// This is the greedy set cover: the next word is always the one with the most syllables
+/// not covered yet, preferring the more frequent then the shorter word. Words with broken
+/// clusters are skipped.
+pub fn cover(
+    input: impl BufRead,
+    script: Script,
+    norm: Normalization,
+    backend: Backend,
+    by: CoverBy,
+) {
+    let mut words: Vec<Word> = Vec::new();
+    let mut word_index: HashMap<String, usize> = HashMap::new();
+    let mut key_index = HashMap::new();
+    for line in input.lines() {
+        let line = line.unwrap();
+        let (count, text) = split_count(&line);
+        let text = norm.normalize(text);
+        for word in text.split_whitespace() {
+            let clusters: Vec<_> = segment_with(word, script, backend).collect();
+            if clusters.is_empty() || clusters.iter().any(|cluster| cluster.broken) {
+                continue;
+            }
+            match word_index.entry(word.to_string()) {
+                Entry::Occupied(entry) => {
+                    words[*entry.get()].count += count;
+                    continue;
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(words.len());
+                }
+            }
+            let mut keys = Vec::new();
+            for cluster in clusters {
+                let key = match by {
+                    CoverBy::Syllable => cluster.text.to_string(),
+                    CoverBy::Pattern => pattern(cluster.text, script),
+                };
+                let next = key_index.len();
+                keys.push(*key_index.entry(key).or_insert(next));
+            }
+            keys.sort_unstable();
+            keys.dedup();
+            words.push(Word {
+                text: word.to_string(),
+                count,
+                keys,
+            });
+        }
+    }
+
+    let mut covered = vec![false; key_index.len()];
+    let mut chosen = Vec::new();
+    // Lazy greedy: the gain of a word can only go down as more keys are covered, so a word
+    // whose recomputed gain is still the best in the heap is the best word.
+    let priority = |w: &Word, gain: usize| (gain, w.count, Reverse(w.text.chars().count()));
+    let mut heap: BinaryHeap<_> = words
+        .iter()
+        .enumerate()
+        .map(|(i, w)| (priority(w, w.keys.len()), Reverse(i)))
+        .collect();
+    while let Some(((gain, _, _), Reverse(i))) = heap.pop() {
+        let word = &words[i];
+        let new_gain = word.keys.iter().filter(|&&k| !covered[k]).count();
+        if new_gain == 0 {
+            continue;
+        }
+        if new_gain < gain {
+            heap.push((priority(word, new_gain), Reverse(i)));
+            continue;
+        }
+        for &k in &word.keys {
+            covered[k] = true;
+        }
+        chosen.push(word.text.as_str());
+    }
+
+    let mut line = String::new();
+    for word in &chosen {
+        if !line.is_empty() && line.chars().count() + word.chars().count() >= LINE_WIDTH {
+            println!("{}", line);
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        println!("{}", line);
+    }
+    let what = match by {
+        CoverBy::Syllable => "syllables",
+        CoverBy::Pattern => "patterns",
+    };
+    eprintln!(
+        "{} words out of {} cover {} {}",
+        chosen.len(),
+        words.len(),
+        key_index.len(),
+        what
+    );
+}
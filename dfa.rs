@@ -0,0 +1,221 @@
+//! Deterministic automata for matching syllables, compiled from regular expressions over
+//! character categories, like the Ragel machines used by HarfBuzz.
+//!
+//! A grammar is a list of rules, each a `Regex` and the kind of syllable it matches. The
+//! automaton finds the longest match of any rule, preferring the earlier rule on a tie,
+//! which is how `match_syllable` chooses between the combinator grammars.
+
+use std::collections::HashMap;
+
+use super::Syllable;
+
+/// Tests whether a character is in some category.
+pub type Predicate = fn(char) -> bool;
+
+/// A regular expression over characters, matched one character at a time by predicates.
+#[derive(Clone)]
+pub enum Regex {
+    One(Predicate),
+    Seq(Vec<Regex>),
+    Either(Vec<Regex>),
+    Optional(Box<Regex>),
+    /// Up to this many repetitions
+    Upto(usize, Box<Regex>),
+}
+
+pub fn one(f: Predicate) -> Regex {
+    Regex::One(f)
+}
+
+pub fn seq(rs: Vec<Regex>) -> Regex {
+    Regex::Seq(rs)
+}
+
+pub fn either(rs: Vec<Regex>) -> Regex {
+    Regex::Either(rs)
+}
+
+pub fn optional(r: Regex) -> Regex {
+    Regex::Optional(Box::new(r))
+}
+
+pub fn upto(max: usize, r: Regex) -> Regex {
+    Regex::Upto(max, Box::new(r))
+}
+
+/// Marks a state of the automaton with no way forward.
+const DEAD: u32 = u32::MAX;
+
+pub struct Dfa {
+    /// The next state for each state and category, at `state * categories + category`
+    next: Vec<u32>,
+    /// The syllable matched by each state, if it is accepting
+    accept: Vec<Option<Syllable>>,
+    categories: usize,
+    category: fn(char) -> usize,
+}
+
+impl Dfa {
+    /// Compiles `rules` for characters in `categories` categories, given by `category`.
+    ///
+    /// Characters in the same category must match the same predicates.
+    pub fn new(rules: &[(Regex, Syllable)], categories: usize, category: fn(char) -> usize) -> Dfa {
+        // one character of each category, to test against the predicates
+        let mut examples = vec![None; categories];
+        for ch in (0..=0x10FFFF).filter_map(std::char::from_u32) {
+            let example = &mut examples[category(ch)];
+            if example.is_none() {
+                *example = Some(ch);
+            }
+        }
+
+        let mut nfa = Nfa::default();
+        let start = nfa.add(None);
+        for (rule, (regex, _)) in rules.iter().enumerate() {
+            let accept = nfa.add(Some(rule));
+            let first = nfa.compile(regex, accept);
+            nfa.states[start].epsilon.push(first);
+        }
+
+        // the subset construction, where each state is a set of NFA states
+        let mut dfa = Dfa {
+            next: Vec::new(),
+            accept: Vec::new(),
+            categories,
+            category,
+        };
+        let mut sets = vec![nfa.closure(vec![start])];
+        let mut ids = HashMap::new();
+        ids.insert(sets[0].clone(), 0);
+        let mut i = 0;
+        while i < sets.len() {
+            let set = sets[i].clone();
+            let rule = set.iter().filter_map(|&s| nfa.states[s].accept).min();
+            dfa.accept.push(rule.map(|rule| rules[rule].1));
+            for example in &examples {
+                let targets: Vec<usize> = match *example {
+                    Some(ch) => set
+                        .iter()
+                        .filter_map(|&s| nfa.states[s].edge)
+                        .filter(|&(f, _)| f(ch))
+                        .map(|(_, target)| target)
+                        .collect(),
+                    None => Vec::new(),
+                };
+                if targets.is_empty() {
+                    dfa.next.push(DEAD);
+                    continue;
+                }
+                let target = nfa.closure(targets);
+                let next = sets.len() as u32;
+                let id = *ids.entry(target.clone()).or_insert_with(|| {
+                    sets.push(target);
+                    next
+                });
+                dfa.next.push(id);
+            }
+            i += 1;
+        }
+        dfa
+    }
+
+    /// Matches the longest syllable at the start of `cs`, returning its length in bytes.
+    ///
+    /// Nothing is matched by an empty syllable.
+    pub fn match_syllable(&self, cs: &str) -> Option<(usize, Syllable)> {
+        let mut state = 0;
+        let mut best = None;
+        for (i, ch) in cs.char_indices() {
+            state = self.next[state as usize * self.categories + (self.category)(ch)];
+            if state == DEAD {
+                break;
+            }
+            if let Some(kind) = self.accept[state as usize] {
+                best = Some((i + ch.len_utf8(), kind));
+            }
+        }
+        best
+    }
+}
+
+#[derive(Default)]
+struct NfaState {
+    /// A character matching the predicate moves to the target state
+    edge: Option<(Predicate, usize)>,
+    epsilon: Vec<usize>,
+    /// The index of the rule matched on reaching this state
+    accept: Option<usize>,
+}
+
+#[derive(Default)]
+struct Nfa {
+    states: Vec<NfaState>,
+}
+
+impl Nfa {
+    fn add(&mut self, accept: Option<usize>) -> usize {
+        self.states.push(NfaState {
+            accept,
+            ..NfaState::default()
+        });
+        self.states.len() - 1
+    }
+
+    /// Adds states matching `regex` and then continuing at `next`, returning the first.
+    fn compile(&mut self, regex: &Regex, next: usize) -> usize {
+        match *regex {
+            Regex::One(f) => {
+                let state = self.add(None);
+                self.states[state].edge = Some((f, next));
+                state
+            }
+            Regex::Seq(ref rs) => rs.iter().rev().fold(next, |next, r| self.compile(r, next)),
+            Regex::Either(ref rs) => {
+                let state = self.add(None);
+                for r in rs {
+                    let first = self.compile(r, next);
+                    self.states[state].epsilon.push(first);
+                }
+                state
+            }
+            Regex::Optional(ref r) => {
+                let state = self.add(None);
+                let first = self.compile(r, next);
+                self.states[state].epsilon.extend(&[first, next]);
+                state
+            }
+            Regex::Upto(max, ref r) => {
+                let mut first = next;
+                for _ in 0..max {
+                    let state = self.add(None);
+                    let more = self.compile(r, first);
+                    self.states[state].epsilon.extend(&[more, next]);
+                    first = state;
+                }
+                first
+            }
+        }
+    }
+
+    /// The states reachable from `states` without reading a character that read one or
+    /// accept, sorted.
+    fn closure(&self, mut states: Vec<usize>) -> Vec<usize> {
+        let mut seen = vec![false; self.states.len()];
+        let mut i = 0;
+        for &s in &states {
+            seen[s] = true;
+        }
+        while i < states.len() {
+            for &s in &self.states[states[i]].epsilon {
+                if !seen[s] {
+                    seen[s] = true;
+                    states.push(s);
+                }
+            }
+            i += 1;
+        }
+        states.retain(|&s| self.states[s].edge.is_some() || self.states[s].accept.is_some());
+        states.sort_unstable();
+        states
+    }
+}
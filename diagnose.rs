@@ -0,0 +1,247 @@
+//! Explaining why a character could not be attached to any syllable.
+//!
+//! Each bad cluster is given a cause from the character and the one before it. That
+//! separates errors in the text, such as marks typed after a letter of another script,
+//! from gaps in the character tables.
+
+use std::ops::Range;
+
+use super::{indic, indic_name, khmer, myanmar, universal, Script};
+
+/// What a character does in a syllable, from its shaping class.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Role {
+    Base,
+    VowelIndependent,
+    /// A virama, invisible stacker or other killer of the inherent vowel
+    Virama,
+    VowelDependent,
+    /// Any other mark, such as a bindu, nukta, medial or tone mark
+    Mark,
+    Joiner,
+    /// Numbers, symbols and other characters that take no marks
+    Other,
+}
+
+impl Role {
+    fn is_mark(self) -> bool {
+        matches!(self, Role::Virama | Role::VowelDependent | Role::Mark)
+    }
+}
+
+/// Why a character could not be attached to any syllable.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Cause {
+    /// A combining mark from U+0300 to U+036F, such as an accent in a transliteration
+    LatinMark,
+    /// A mark after a letter of another script
+    ForeignBase,
+    /// The same mark twice in a row, such as a doubled matra
+    DuplicatedMark,
+    /// A virama after an independent vowel
+    ViramaAfterVowel,
+    /// A ZWJ or ZWNJ where the grammar does not allow one
+    StrayJoiner,
+    /// Any other mark where the grammar does not allow one, such as at the start of a word
+    MisplacedMark,
+    /// An unassigned code point in the blocks of the script (Indic scripts only)
+    Unassigned,
+    /// A character in the blocks of the script without a shaping class
+    NotInTable,
+    /// A character outside the blocks of the script
+    OtherScript,
+    /// Anything else, such as a base the grammar did not accept
+    Unknown,
+}
+
+/// Where the cause of a failure is likely to be fixed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Origin {
+    /// The text is wrong, for example because of its encoding or a typing error
+    Text,
+    /// The character tables are missing a character
+    Tables,
+    /// Either the grammar is too strict or the text is wrong
+    Grammar,
+}
+
+impl Cause {
+    pub fn name(self) -> &'static str {
+        match self {
+            Cause::LatinMark => "latin-mark",
+            Cause::ForeignBase => "foreign-base",
+            Cause::DuplicatedMark => "duplicated-mark",
+            Cause::ViramaAfterVowel => "virama-after-vowel",
+            Cause::StrayJoiner => "stray-joiner",
+            Cause::MisplacedMark => "misplaced-mark",
+            Cause::Unassigned => "unassigned",
+            Cause::NotInTable => "not-in-table",
+            Cause::OtherScript => "other-script",
+            Cause::Unknown => "unknown",
+        }
+    }
+
+    pub fn origin(self) -> Origin {
+        match self {
+            Cause::LatinMark
+            | Cause::ForeignBase
+            | Cause::DuplicatedMark
+            | Cause::ViramaAfterVowel
+            | Cause::Unassigned
+            | Cause::OtherScript => Origin::Text,
+            Cause::NotInTable => Origin::Tables,
+            Cause::StrayJoiner | Cause::MisplacedMark | Cause::Unknown => Origin::Grammar,
+        }
+    }
+}
+
+impl Origin {
+    pub const ALL: [Origin; 3] = [Origin::Text, Origin::Tables, Origin::Grammar];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Origin::Text => "text",
+            Origin::Tables => "tables",
+            Origin::Grammar => "grammar",
+        }
+    }
+}
+
+/// Finds why the cluster at `range` of `text` failed: either a character that could not be
+/// attached to any syllable, or a broken syllable, which has marks without a base.
+///
+/// The cause comes from the first character of the cluster that is not a base, and the
+/// character before it.
+pub fn diagnose(text: &str, range: Range<usize>, script: Script) -> Cause {
+    let cluster = &text[range.clone()];
+    let offset = cluster
+        .char_indices()
+        .find(|&(_, ch)| role_of(ch, script) != Some(Role::Base))
+        .map_or(0, |(i, _)| i);
+    let start = range.start + offset;
+    let ch = match text[start..].chars().next() {
+        Some(ch) => ch,
+        None => return Cause::Unknown,
+    };
+    let previous = text[..start].chars().next_back();
+    if ('\u{0300}'..='\u{036F}').contains(&ch) {
+        return Cause::LatinMark;
+    }
+    let role = role_of(ch, script);
+    if role == Some(Role::Joiner) {
+        return Cause::StrayJoiner;
+    }
+    if !in_script(ch, script) {
+        return Cause::OtherScript;
+    }
+    let role = match role {
+        Some(role) => role,
+        None if uses_indic(script) && indic_name(ch).is_none() => return Cause::Unassigned,
+        None => return Cause::NotInTable,
+    };
+    if !role.is_mark() {
+        return Cause::Unknown;
+    }
+    match previous {
+        Some(p) if p == ch => Cause::DuplicatedMark,
+        Some(p) if role == Role::Virama && role_of(p, script) == Some(Role::VowelIndependent) => {
+            Cause::ViramaAfterVowel
+        }
+        // a mark after a space, digit or punctuation is merely misplaced
+        Some(p) if p.is_alphabetic() && !in_script(p, script) => Cause::ForeignBase,
+        _ => Cause::MisplacedMark,
+    }
+}
+
+fn role_of(ch: char, script: Script) -> Option<Role> {
+    match script {
+        Script::Myanmar => myanmar::role(ch),
+        Script::Khmer => khmer::role(ch),
+        _ if !uses_indic(script) => universal::role(ch),
+        _ => indic::role(ch),
+    }
+}
+
+fn uses_indic(script: Script) -> bool {
+    !matches!(
+        script,
+        Script::Myanmar
+            | Script::Khmer
+            | Script::Balinese
+            | Script::Javanese
+            | Script::TaiTham
+            | Script::Chakma
+            | Script::Sharada
+            | Script::Tirhuta
+            | Script::Kawi
+    )
+}
+
+/// Characters shared by the Indic scripts: the dandas, Vedic Extensions and Common Indic
+/// Number Forms
+const INDIC_COMMON: &[(u32, u32)] = &[(0x0964, 0x0965), (0x1CD0, 0x1CFF), (0xA830, 0xA83F)];
+
+/// Whether `ch` is in one of the Unicode blocks of `script`.
+pub(crate) fn in_script(ch: char, script: Script) -> bool {
+    let blocks: &[(u32, u32)] = match script {
+        Script::Devanagari => &[(0x0900, 0x097F), (0xA8E0, 0xA8FF), (0x11B00, 0x11B5F)],
+        Script::Bengali => &[(0x0980, 0x09FF)],
+        Script::Tamil => &[(0x0B80, 0x0BFF), (0x11FC0, 0x11FFF)],
+        Script::Telugu => &[(0x0C00, 0x0C7F)],
+        Script::Gujarati => &[(0x0A80, 0x0AFF)],
+        Script::Gurmukhi => &[(0x0A00, 0x0A7F)],
+        Script::Oriya => &[(0x0B00, 0x0B7F)],
+        Script::Malayalam => &[(0x0D00, 0x0D7F)],
+        Script::Myanmar => &[(0x1000, 0x109F), (0xA9E0, 0xA9FF), (0xAA60, 0xAA7F)],
+        Script::Kannada => &[(0x0C80, 0x0CFF)],
+        Script::Sinhala => &[(0x0D80, 0x0DFF), (0x111E0, 0x111FF)],
+        Script::Khmer => &[(0x1780, 0x17FF), (0x19E0, 0x19FF)],
+        Script::Balinese => &[(0x1B00, 0x1B7F)],
+        Script::Javanese => &[(0xA980, 0xA9DF)],
+        Script::TaiTham => &[(0x1A20, 0x1AAF)],
+        Script::Chakma => &[(0x11100, 0x1114F)],
+        Script::Sharada => &[(0x11180, 0x111DF)],
+        Script::Tirhuta => &[(0x11480, 0x114DF)],
+        Script::Kawi => &[(0x11F00, 0x11F5F)],
+    };
+    let common = if uses_indic(script) {
+        INDIC_COMMON
+    } else {
+        &[]
+    };
+    let cp = ch as u32;
+    blocks
+        .iter()
+        .chain(common)
+        .any(|&(first, last)| first <= cp && cp <= last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cause(text: &str, script: Script) -> Cause {
+        let start = text.char_indices().last().map_or(0, |(i, _)| i);
+        diagnose(text, start..text.len(), script)
+    }
+
+    #[test]
+    fn mark_after_foreign_letter() {
+        assert_eq!(cause("a\u{093F}", Script::Devanagari), Cause::ForeignBase);
+        assert_eq!(
+            cause("\u{0995}\u{093F}", Script::Devanagari),
+            Cause::ForeignBase
+        );
+    }
+
+    #[test]
+    fn mark_after_space_or_punctuation() {
+        assert_eq!(cause(" \u{093F}", Script::Devanagari), Cause::MisplacedMark);
+        assert_eq!(
+            cause("\u{0915} \u{093F}", Script::Devanagari),
+            Cause::MisplacedMark
+        );
+        assert_eq!(cause("(\u{093F}", Script::Devanagari), Cause::MisplacedMark);
+        assert_eq!(cause("2\u{093F}", Script::Devanagari), Cause::MisplacedMark);
+    }
+}
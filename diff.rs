@@ -0,0 +1,177 @@
+//! Comparing the syllable inventories written by two runs of `syllables`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use corpus::{parse_bad_line, BadCluster};
+
+use super::friendly;
+
+/// The syllables and bad clusters in a `data/syl.SCRIPT` file.
+///
+/// Bad clusters are compared by their character alone, as older reports give the text after
+/// the character and newer ones the cluster before it. The first report of each character
+/// is kept.
+#[derive(Default)]
+struct Inventory {
+    good: BTreeSet<String>,
+    bad: BTreeMap<char, BadCluster>,
+}
+
+impl Inventory {
+    fn read(path: &Path) -> io::Result<Inventory> {
+        let mut inventory = Inventory::default();
+        let file = File::open(path)
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))?;
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.starts_with("bad: ") {
+                match parse_bad_line(&line) {
+                    Some(s) => {
+                        inventory.bad.entry(s.character()).or_insert(s);
+                    }
+                    None => {
+                        let msg = format!("{}: malformed line: {}", path.display(), line);
+                        return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+                    }
+                }
+            } else if !line.is_empty() {
+                inventory.good.insert(line);
+            }
+        }
+        Ok(inventory)
+    }
+
+    /// Reads an inventory that is only in one of two directories as empty.
+    fn read_if_exists(path: &Path) -> io::Result<Inventory> {
+        if path.exists() {
+            Inventory::read(path)
+        } else {
+            Ok(Inventory::default())
+        }
+    }
+
+    /// Returns the longest syllable in the text of `bad` that includes the character that
+    /// failed.
+    fn good_syllable<'a>(&self, bad: &'a BadCluster) -> Option<&'a str> {
+        let text = &bad.text;
+        let starts = text
+            .char_indices()
+            .map(|(i, _)| i)
+            .filter(|&i| i <= bad.start);
+        let ends: Vec<usize> = text
+            .char_indices()
+            .map(|(i, c)| i + c.len_utf8())
+            .filter(|&j| j > bad.start)
+            .collect();
+        starts
+            .flat_map(|i| ends.iter().map(move |&j| &text[i..j]))
+            .filter(|s| self.good.contains(*s))
+            .max_by_key(|s| s.len())
+    }
+}
+
+/// Prints the differences between two inventories, either two `syl.*` files or two
+/// directories of them, one report per script.
+pub fn diff(old: &Path, new: &Path) -> io::Result<()> {
+    if !old.is_dir() {
+        return diff_files(old, new, Inventory::read(old)?, Inventory::read(new)?);
+    }
+    let mut names = BTreeSet::new();
+    for dir in &[old, new] {
+        for entry in fs::read_dir(dir)? {
+            let name = entry?.file_name().to_string_lossy().into_owned();
+            if name.starts_with("syl.") {
+                names.insert(name);
+            }
+        }
+    }
+    for name in names {
+        let (old_path, new_path) = (old.join(&name), new.join(&name));
+        let old = Inventory::read_if_exists(&old_path)?;
+        let new = Inventory::read_if_exists(&new_path)?;
+        diff_files(&old_path, &new_path, old, new)?;
+    }
+    Ok(())
+}
+
+fn diff_files(old_path: &Path, new_path: &Path, old: Inventory, new: Inventory) -> io::Result<()> {
+    let script = new_path
+        .extension()
+        .or_else(|| old_path.extension())
+        .map_or_else(
+            || new_path.display().to_string(),
+            |ext| ext.to_string_lossy().into_owned(),
+        );
+
+    let added: Vec<&String> = new.good.difference(&old.good).collect();
+    let removed: Vec<&String> = old.good.difference(&new.good).collect();
+    // A bad cluster that is gone became good if its character is now part of a syllable,
+    // and a new bad cluster used to be good if its character was part of one before
+    let mut fixed = Vec::new();
+    let mut bad_removed = Vec::new();
+    for (_, s) in old.bad.iter().filter(|(ch, _)| !new.bad.contains_key(ch)) {
+        match new.good_syllable(s) {
+            Some(syllable) => fixed.push((s, syllable)),
+            None => bad_removed.push(s),
+        }
+    }
+    let mut broke = Vec::new();
+    let mut bad_added = Vec::new();
+    for (_, s) in new.bad.iter().filter(|(ch, _)| !old.bad.contains_key(ch)) {
+        match old.good_syllable(s) {
+            Some(syllable) => broke.push((s, syllable)),
+            None => bad_added.push(s),
+        }
+    }
+
+    println!(
+        "{}: {} new, {} disappeared, {} bad became good, {} good became bad, {} new bad, {} bad disappeared",
+        script,
+        added.len(),
+        removed.len(),
+        fixed.len(),
+        broke.len(),
+        bad_added.len(),
+        bad_removed.len()
+    );
+    print_syllables("new syllables", &added);
+    print_syllables("disappeared syllables", &removed);
+    print_changes("bad clusters that became good", &fixed);
+    print_changes("good clusters that became bad", &broke);
+    print_bad("new bad clusters", &bad_added);
+    print_bad("disappeared bad clusters", &bad_removed);
+    Ok(())
+}
+
+fn print_syllables(title: &str, syllables: &[&String]) {
+    if syllables.is_empty() {
+        return;
+    }
+    println!("  {} ({}):", title, syllables.len());
+    for s in syllables {
+        println!("    {}\t{}", s, friendly(s));
+    }
+}
+
+fn print_changes(title: &str, changes: &[(&BadCluster, &str)]) {
+    if changes.is_empty() {
+        return;
+    }
+    println!("  {} ({}):", title, changes.len());
+    for (bad, syllable) in changes {
+        println!("    {:?} as {}\t{}", bad.text, syllable, friendly(syllable));
+    }
+}
+
+fn print_bad(title: &str, bad: &[&BadCluster]) {
+    if bad.is_empty() {
+        return;
+    }
+    println!("  {} ({}):", title, bad.len());
+    for bad in bad {
+        println!("    {:?} {}", bad.text, friendly(&bad.text));
+    }
+}
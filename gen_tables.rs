@@ -0,0 +1,557 @@
+//! Generates the character tables in indic.rs and myanmar.rs from the Unicode Character
+//! Database, applying the overrides for places where the OpenType shaping documents deviate
+//! from Unicode.
+
+use std::char;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process;
+
+struct Table {
+    name: &'static str,
+    /// Function signature and prelude, up to the first match arm
+    header: &'static str,
+    /// Signature of the character name function, if the table has one
+    names_header: Option<&'static str>,
+    /// The code points in each of the character tables of the shaping documents
+    sections: &'static [(&'static str, &'static [(u32, u32)])],
+    /// Script prefixes stripped from Unicode character names
+    prefixes: &'static [&'static str],
+    classes: &'static [&'static str],
+    positions: &'static [&'static str],
+    /// Indic category values with a variant name that isn't simply the value in camel case
+    class_renames: &'static [(&'static str, &'static str)],
+    /// Emit `unassigned` arms for unassigned code points in the sections
+    unassigned: bool,
+    /// Show the character itself in the comment before its name
+    show_char: bool,
+}
+
+const POSITION_RENAMES: &[(&str, &str)] = &[
+    ("Top_And_Left_And_Right", "TopLeftAndRightPosition"),
+    ("Top_And_Bottom_And_Left", "TopLeftAndBottomPosition"),
+    ("Overstruck", "Overstruck"),
+];
+
+const INDIC: Table = Table {
+    name: "indic",
+    header: "#[rustfmt::skip]
+pub fn indic_character(ch: char) -> (Option<ShapingClass>, Option<MarkPlacementSubclass>) {
+    use MarkPlacementSubclass::*;
+    use ShapingClass::*;
+
+    match ch as u32 {",
+    names_header: Some(
+        "pub fn indic_name(ch: char) -> Option<&'static str> {
+    match ch as u32 {",
+    ),
+    sections: &[
+        ("Devanagari", &[(0x0900, 0x097F)]),
+        ("Bengali", &[(0x0980, 0x09FF)]),
+        ("Gurmukhi", &[(0x0A00, 0x0A7F)]),
+        ("Gujarati", &[(0x0A80, 0x0AFF)]),
+        ("Oriya", &[(0x0B00, 0x0B7F)]),
+        ("Tamil", &[(0x0B80, 0x0BFF)]),
+        ("Telugu", &[(0x0C00, 0x0C7F)]),
+        ("Kannada", &[(0x0C80, 0x0CFF)]),
+        ("Malayalam", &[(0x0D00, 0x0D7F)]),
+        ("Sinhala", &[(0x0D80, 0x0DFF)]),
+        ("Vedic Extensions", &[(0x1CD0, 0x1CFF)]),
+        ("Devanagari Extended", &[(0xA8E0, 0xA8FF)]),
+        ("Sinhala Archaic Numbers", &[(0x111E0, 0x111FF)]),
+        (
+            "Grantha marks",
+            &[(0x11301, 0x11301), (0x11303, 0x11303), (0x1133B, 0x1133C)],
+        ),
+        (
+            "Miscellaneous",
+            &[
+                (0x00A0, 0x00A0),
+                (0x00B2, 0x00B3),
+                (0x200C, 0x200D),
+                (0x2010, 0x2014),
+                (0x2074, 0x2074),
+                (0x2082, 0x2084),
+                (0x25CC, 0x25CC),
+            ],
+        ),
+    ],
+    prefixes: &[
+        "DEVANAGARI",
+        "BENGALI",
+        "GURMUKHI",
+        "GUJARATI",
+        "ORIYA",
+        "TAMIL",
+        "TELUGU",
+        "KANNADA",
+        "MALAYALAM",
+        "SINHALA",
+    ],
+    classes: &[
+        "Bindu",
+        "Visarga",
+        "Avagraha",
+        "Nukta",
+        "Virama",
+        "Cantillation",
+        "GeminationMark",
+        "PureKiller",
+        "SyllableModifier",
+        "Consonant",
+        "VowelIndependent",
+        "VowelDependent",
+        "ConsonantDead",
+        "ConsonantMedial",
+        "ConsonantPlaceholder",
+        "ConsonantWithStacker",
+        "ConsonantPreRepha",
+        "ModifyingLetter",
+        "Placeholder",
+        "Number",
+        "Symbol",
+        "Joiner",
+        "NonJoiner",
+        "DottedCircle",
+    ],
+    positions: &[
+        "TopPosition",
+        "RightPosition",
+        "BottomPosition",
+        "LeftPosition",
+        "LeftAndRightPosition",
+        "TopAndRightPosition",
+        "TopAndLeftPosition",
+        "TopLeftAndRightPosition",
+        "TopAndBottomPosition",
+        "Overstruck",
+    ],
+    class_renames: &[
+        ("Cantillation_Mark", "Cantillation"),
+        ("Consonant_Preceding_Repha", "ConsonantPreRepha"),
+    ],
+    unassigned: true,
+    show_char: false,
+};
+
+const MYANMAR: Table = Table {
+    name: "myanmar",
+    header:
+        "fn myanmar_character(ch: char) -> (Option<ShapingClass>, Option<MarkPlacementSubclass>) {
+    use self::MarkPlacementSubclass::*;
+    use self::ShapingClass::*;
+
+    match ch as u32 {",
+    names_header: None,
+    sections: &[
+        ("Myanmar", &[(0x1000, 0x109F)]),
+        ("Myanmar Extended A", &[(0xAA60, 0xAA7F)]),
+        ("Myanmar Extended B", &[(0xA9E0, 0xA9FF)]),
+        (
+            "Miscellaneous",
+            &[
+                (0x00A0, 0x00A0),
+                (0x200C, 0x200D),
+                (0x2010, 0x2014),
+                (0x25CC, 0x25CC),
+            ],
+        ),
+    ],
+    prefixes: &["MYANMAR"],
+    classes: &[
+        "Bindu",
+        "Visarga",
+        "PureKiller",
+        "Consonant",
+        "VowelIndependent",
+        "VowelDependent",
+        "ConsonantMedial",
+        "ConsonantPlaceholder",
+        "Number",
+        "Symbol",
+        "ToneMarker",
+        "InvisibleStacker",
+        "ConsonantWithStacker",
+        "Placeholder",
+        "Joiner",
+        "NonJoiner",
+        "DottedCircle",
+    ],
+    positions: &[
+        "TopPosition",
+        "RightPosition",
+        "BottomPosition",
+        "LeftPosition",
+        "TopLeftAndBottomPosition",
+    ],
+    class_renames: &[("Tone_Mark", "ToneMarker")],
+    unassigned: false,
+    show_char: true,
+};
+
+/// A correction from the overrides file; `None` fields keep the value derived from Unicode.
+struct Override {
+    class: Option<Option<String>>,
+    position: Option<Option<String>>,
+}
+
+struct Entry {
+    cp: u32,
+    class: Option<String>,
+    position: Option<String>,
+    name: Option<String>,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let check = match args.len() {
+        4 => None,
+        6 if args[4] == "--check" => Some(&args[5]),
+        _ => {
+            println!("Usage: gen-tables UCD_DIR OVERRIDES [indic|myanmar] [--check SOURCE]");
+            return;
+        }
+    };
+    let table = match args[3].as_str() {
+        "indic" => &INDIC,
+        "myanmar" => &MYANMAR,
+        _ => {
+            println!("unknown table");
+            return;
+        }
+    };
+    let dir = Path::new(&args[1]);
+    let categories = read_property(&dir.join("IndicSyllabicCategory.txt"));
+    let positions = read_property(&dir.join("IndicPositionalCategory.txt"));
+    let unicode_data = read_unicode_data(&dir.join("UnicodeData.txt"));
+    let overrides = read_overrides(Path::new(&args[2]), table.name);
+
+    let mut sections = Vec::new();
+    for &(title, ranges) in table.sections {
+        let mut entries = Vec::new();
+        for &(first, last) in ranges {
+            for cp in first..=last {
+                let mut entry = match unicode_data.get(&cp) {
+                    Some((name, gc)) => Entry {
+                        cp,
+                        class: derive_class(table, cp, categories.get(&cp), gc),
+                        position: derive_position(table, cp, positions.get(&cp)),
+                        name: Some(derive_name(table, name)),
+                    },
+                    None => Entry {
+                        cp,
+                        class: None,
+                        position: None,
+                        name: None,
+                    },
+                };
+                if let Some(o) = overrides.get(&cp) {
+                    if let Some(ref class) = o.class {
+                        entry.class = class.clone();
+                    }
+                    if let Some(ref position) = o.position {
+                        entry.position = position.clone();
+                    }
+                }
+                if entry.name.is_some() || table.unassigned {
+                    entries.push(entry);
+                }
+            }
+        }
+        sections.push((title, entries));
+    }
+
+    let mut out = table_source(table, &sections);
+    if let Some(header) = table.names_header {
+        out.push('\n');
+        out.push_str(&names_source(header, &sections));
+    }
+    match check {
+        Some(path) => check_source(table, &out, Path::new(path)),
+        None => print!("{}", out),
+    }
+}
+
+/// Checks that the functions in `source` have the same match arms as the generated ones,
+/// ignoring whitespace, and exits with an error at the first difference.
+fn check_source(table: &Table, generated: &str, path: &Path) {
+    let source = match fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("{}: {}", path.display(), err);
+            process::exit(1);
+        }
+    };
+    let headers = Some(table.header).into_iter().chain(table.names_header);
+    for header in headers {
+        let signature = header.lines().find(|l| l.contains("fn ")).unwrap();
+        let expected = function_lines(generated, signature).unwrap();
+        let found = match function_lines(&source, signature) {
+            Some(lines) => lines,
+            None => {
+                eprintln!("{}: no {}", path.display(), signature);
+                process::exit(1);
+            }
+        };
+        let first_difference = expected.iter().zip(&found).position(|(e, f)| e != f);
+        if let Some(i) = first_difference {
+            eprintln!(
+                "{}: {} differs from the generated table",
+                path.display(),
+                signature
+            );
+            eprintln!("  generated: {}", expected[i]);
+            eprintln!("  found:     {}", found[i]);
+            process::exit(1);
+        }
+    }
+}
+
+/// The lines of the function starting with `signature`, up to the end of its match, with
+/// whitespace collapsed and blank lines left out. As both lists end with the closing brace,
+/// a missing or extra arm shows up as a differing line.
+fn function_lines(text: &str, signature: &str) -> Option<Vec<String>> {
+    let mut lines = text.lines().skip_while(|l| !l.starts_with(signature));
+    let first = lines.next()?;
+    let mut function = vec![first.to_string()];
+    for line in lines {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if line == "}" {
+            function.push(line);
+            break;
+        }
+        if !line.is_empty() {
+            function.push(line);
+        }
+    }
+    Some(function)
+}
+
+fn table_source(table: &Table, sections: &[(&str, Vec<Entry>)]) -> String {
+    let mut out = String::new();
+    writeln!(out, "{}", table.header).unwrap();
+    for (i, (title, entries)) in sections.iter().enumerate() {
+        if i > 0 {
+            writeln!(out).unwrap();
+        }
+        writeln!(out, "        // {} character table", title).unwrap();
+        let arms: Vec<String> = entries
+            .iter()
+            .map(|e| {
+                format!(
+                    "0x{:04X} => ({}, {}),",
+                    e.cp,
+                    variant(&e.class),
+                    variant(&e.position)
+                )
+            })
+            .collect();
+        let width = arms.iter().map(|s| s.len()).max().unwrap_or(0);
+        for (arm, e) in arms.iter().zip(entries) {
+            let name = e.name.as_ref().map_or("unassigned", String::as_str);
+            let comment = match char::from_u32(e.cp) {
+                Some(ch) if table.show_char => format!("{} {}", ch, name),
+                _ => name.to_string(),
+            };
+            writeln!(out, "        {:width$} // {}", arm, comment, width = width).unwrap();
+        }
+    }
+    writeln!(out).unwrap();
+    writeln!(out, "        _ => (None, None),").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+fn names_source(header: &str, sections: &[(&str, Vec<Entry>)]) -> String {
+    let mut out = String::new();
+    writeln!(out, "{}", header).unwrap();
+    for (i, (title, entries)) in sections.iter().enumerate() {
+        if i > 0 {
+            writeln!(out).unwrap();
+        }
+        writeln!(out, "        // {} character table", title).unwrap();
+        for e in entries {
+            match e.name {
+                Some(ref name) => writeln!(out, "        0x{:04X} => Some({:?}),", e.cp, name),
+                None => writeln!(out, "        0x{:04X} => None,", e.cp),
+            }
+            .unwrap();
+        }
+    }
+    writeln!(out).unwrap();
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+fn variant(v: &Option<String>) -> String {
+    match v {
+        Some(v) => format!("Some({})", v),
+        None => String::from("None"),
+    }
+}
+
+/// Maps an Indic_Syllabic_Category value to a `ShapingClass` variant.
+///
+/// Characters without a category fall back on their general category, as numbers and
+/// symbols are classed by the shaping documents but are `Other` in Unicode.
+fn derive_class(table: &Table, cp: u32, category: Option<&String>, gc: &str) -> Option<String> {
+    let class = match category {
+        Some(value) => match table.class_renames.iter().find(|r| r.0 == value) {
+            Some(&(_, class)) => class.to_string(),
+            None => value.replace('_', ""),
+        },
+        None => match gc {
+            "Nd" | "No" => String::from("Number"),
+            "Sc" | "So" => String::from("Symbol"),
+            _ => return None,
+        },
+    };
+    if table.classes.contains(&class.as_str()) {
+        Some(class)
+    } else {
+        eprintln!("U+{:04X}: no shaping class for {}", cp, class);
+        None
+    }
+}
+
+/// Maps an Indic_Positional_Category value to a `MarkPlacementSubclass` variant.
+fn derive_position(table: &Table, cp: u32, category: Option<&String>) -> Option<String> {
+    let value = category?;
+    let position = match POSITION_RENAMES.iter().find(|r| r.0 == value) {
+        Some(&(_, position)) => position.to_string(),
+        None => format!("{}Position", value.replace('_', "")),
+    };
+    if table.positions.contains(&position.as_str()) {
+        Some(position)
+    } else {
+        eprintln!("U+{:04X}: no mark placement for {}", cp, value);
+        None
+    }
+}
+
+/// Turns a Unicode character name into the shorter form used in the tables, so that
+/// `DEVANAGARI VOWEL SIGN AA` becomes `Sign Aa` and `DEVANAGARI LETTER KA` becomes `Ka`.
+fn derive_name(table: &Table, name: &str) -> String {
+    let name = table
+        .prefixes
+        .iter()
+        .find_map(|p| name.strip_prefix(p).and_then(|s| s.strip_prefix(' ')))
+        .unwrap_or(name);
+    let name = match name.strip_prefix("VOWEL SIGN ") {
+        Some(rest) => format!("SIGN {}", rest),
+        None => ["LETTER ", "SIGN ", "VOWEL "]
+            .iter()
+            .find_map(|p| name.strip_prefix(p))
+            .unwrap_or(name)
+            .to_string(),
+    };
+    let mut s = String::new();
+    let mut in_word = false;
+    for c in name.chars() {
+        if in_word {
+            s.extend(c.to_lowercase());
+        } else {
+            s.push(c);
+        }
+        in_word = c.is_alphabetic();
+    }
+    s
+}
+
+fn open(path: &Path) -> BufReader<File> {
+    match File::open(path) {
+        Ok(file) => BufReader::new(file),
+        Err(err) => {
+            eprintln!("{}: {}", path.display(), err);
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads the fields of a semicolon-separated data file, skipping comments and blank lines.
+fn read_fields(path: &Path) -> Vec<Vec<String>> {
+    let mut lines = Vec::new();
+    for line in open(path).lines() {
+        let line = line.unwrap();
+        let data = line.split('#').next().unwrap().trim();
+        if !data.is_empty() {
+            lines.push(data.split(';').map(|f| f.trim().to_string()).collect());
+        }
+    }
+    lines
+}
+
+fn parse_code_points(s: &str) -> (u32, u32) {
+    let mut iter = s.split("..").map(|cp| u32::from_str_radix(cp, 16).unwrap());
+    let first = iter.next().unwrap();
+    (first, iter.next().unwrap_or(first))
+}
+
+/// Reads a property file such as `IndicSyllabicCategory.txt`.
+fn read_property(path: &Path) -> HashMap<u32, String> {
+    let mut map = HashMap::new();
+    for fields in read_fields(path) {
+        let (first, last) = parse_code_points(&fields[0]);
+        for cp in first..=last {
+            map.insert(cp, fields[1].clone());
+        }
+    }
+    map
+}
+
+/// Reads the name and general category of each character in `UnicodeData.txt`.
+fn read_unicode_data(path: &Path) -> HashMap<u32, (String, String)> {
+    let mut map = HashMap::new();
+    for fields in read_fields(path) {
+        let cp = u32::from_str_radix(&fields[0], 16).unwrap();
+        // skip the ranges of CJK ideographs and the like, which have no names
+        if !fields[1].starts_with('<') {
+            map.insert(cp, (fields[1].clone(), fields[2].clone()));
+        }
+    }
+    map
+}
+
+/// Reads the overrides for one table.
+///
+/// Each line is `TABLE ; CODE POINTS ; CLASS ; POSITION`, where an empty field keeps the value
+/// derived from Unicode and `-` clears it.
+fn read_overrides(path: &Path, table: &str) -> HashMap<u32, Override> {
+    fn field(s: &str) -> Option<Option<String>> {
+        match s {
+            "" => None,
+            "-" => Some(None),
+            _ => Some(Some(s.to_string())),
+        }
+    }
+
+    let mut map = HashMap::new();
+    for fields in read_fields(path) {
+        if fields.len() != 4 {
+            eprintln!("bad override: {}", fields.join(" ; "));
+            process::exit(1);
+        }
+        if fields[0] != table {
+            continue;
+        }
+        let (first, last) = parse_code_points(&fields[1]);
+        for cp in first..=last {
+            let o = Override {
+                class: field(&fields[2]),
+                position: field(&fields[3]),
+            };
+            if map.insert(cp, o).is_some() {
+                eprintln!("U+{:04X}: more than one override", cp);
+                process::exit(1);
+            }
+        }
+    }
+    map
+}
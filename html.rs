@@ -0,0 +1,201 @@
+//! Extracting the text from HTML pages.
+
+use html_escape::decode_html_entities;
+
+/// Elements whose content is not text, such as scripts and style sheets.
+const RAW_TEXT_TAGS: &[&str] = &["script", "style"];
+
+enum State {
+    Text,
+    /// Inside a tag, recording its name, whether it is a closing tag, whether an
+    /// attribute value may start here and the quote character of the attribute value we
+    /// are in, if any
+    Tag {
+        name: String,
+        in_name: bool,
+        closing: bool,
+        after_equals: bool,
+        quote: Option<char>,
+    },
+    Comment,
+    /// Inside a script or style element, waiting for its closing tag
+    RawText(&'static str),
+}
+
+impl State {
+    fn tag(closing: bool) -> State {
+        State::Tag {
+            name: String::new(),
+            in_name: true,
+            closing,
+            after_equals: false,
+            quote: None,
+        }
+    }
+}
+
+/// Extracts the text nodes from an HTML page fed to it one line at a time.
+///
+/// Tags, attribute values, comments and the contents of `<script>` and `<style>` are
+/// skipped, and character references are decoded.
+pub struct TextExtractor {
+    state: State,
+}
+
+impl TextExtractor {
+    pub fn new() -> Self {
+        TextExtractor { state: State::Text }
+    }
+
+    /// Appends the text in `line` to `out`, with a space wherever a tag was.
+    pub fn push_line(&mut self, line: &str, out: &mut String) {
+        let mut rest = line;
+        while !rest.is_empty() {
+            rest = match self.state {
+                State::Text => match rest.find('<') {
+                    Some(i) => {
+                        out.push_str(&decode_html_entities(&rest[..i]));
+                        self.start_markup(&rest[i..], out)
+                    }
+                    None => {
+                        out.push_str(&decode_html_entities(rest));
+                        ""
+                    }
+                },
+                State::Tag { .. } => self.skip_tag(rest),
+                State::Comment => match rest.find("-->") {
+                    Some(i) => {
+                        self.state = State::Text;
+                        &rest[i + 3..]
+                    }
+                    None => "",
+                },
+                State::RawText(name) => match find_closing_tag(rest, name) {
+                    Some(i) => {
+                        self.state = State::tag(true);
+                        &rest[i + 2..]
+                    }
+                    None => "",
+                },
+            };
+        }
+        out.push(' ');
+    }
+
+    /// Starts a tag or comment at the `<` at the start of `s`, which is literal text if it
+    /// isn't followed by a tag name.
+    fn start_markup<'a>(&mut self, s: &'a str, out: &mut String) -> &'a str {
+        if let Some(rest) = s.strip_prefix("<!--") {
+            self.state = State::Comment;
+            out.push(' ');
+            return rest;
+        }
+        let tag = s[1..].starts_with(|c: char| c.is_ascii_alphabetic() || c == '/' || c == '!');
+        if !tag {
+            out.push('<');
+            return &s[1..];
+        }
+        let closing = s[1..].starts_with('/');
+        self.state = State::tag(closing);
+        out.push(' ');
+        if closing {
+            &s[2..]
+        } else {
+            &s[1..]
+        }
+    }
+
+    fn skip_tag<'a>(&mut self, s: &'a str) -> &'a str {
+        let mut end = None;
+        if let State::Tag {
+            name,
+            in_name,
+            after_equals,
+            quote,
+            ..
+        } = &mut self.state
+        {
+            for (i, c) in s.char_indices() {
+                *in_name = *in_name && c.is_ascii_alphanumeric();
+                // a quote only starts a value right after the `=`, elsewhere it is part
+                // of an unquoted value such as `alt=it's`
+                let value_start = *after_equals;
+                if quote.is_none() && !c.is_whitespace() {
+                    *after_equals = c == '=';
+                }
+                match *quote {
+                    Some(q) if c == q => *quote = None,
+                    Some(_) => {}
+                    None if value_start && (c == '"' || c == '\'') => *quote = Some(c),
+                    None if c == '>' => {
+                        end = Some(i + 1);
+                        break;
+                    }
+                    None if *in_name => name.push(c.to_ascii_lowercase()),
+                    None => {}
+                }
+            }
+        }
+        let end = match end {
+            Some(end) => end,
+            None => return "",
+        };
+        self.state = match &self.state {
+            State::Tag {
+                name,
+                closing: false,
+                ..
+            } => match RAW_TEXT_TAGS.iter().find(|t| *t == name) {
+                Some(t) => State::RawText(t),
+                None => State::Text,
+            },
+            _ => State::Text,
+        };
+        &s[end..]
+    }
+}
+
+/// Finds the closing tag of a raw text element, such as `</script>`, in any case.
+fn find_closing_tag(s: &str, name: &str) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(i) = s[offset..].find("</") {
+        let start = offset + i;
+        let tag = s.as_bytes().get(start + 2..start + 2 + name.len());
+        if tag.is_some_and(|t| t.eq_ignore_ascii_case(name.as_bytes())) {
+            return Some(start);
+        }
+        offset = start + 2;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextExtractor;
+
+    fn extract(lines: &[&str]) -> String {
+        let mut extractor = TextExtractor::new();
+        let mut out = String::new();
+        for line in lines {
+            extractor.push_line(line, &mut out);
+        }
+        out.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    #[test]
+    fn attribute_values() {
+        let cases: &[(&[&str], &str)] = &[
+            (&["<p class=\"a > b\">text</p>"], "text"),
+            (&["<p title='it\"s'>text</p>"], "text"),
+            (&["<p class = \"a\">text</p>"], "text"),
+            (&["<p class=", "\"a > b\">text</p>"], "text"),
+            // an apostrophe in an unquoted value does not start a quoted one
+            (&["<p class=don't>one</p> <img alt=it's> two"], "one two"),
+            (&["<p data-x=a\"b>one</p>", "<p>two</p>"], "one two"),
+            (&["<input disabled 'x'>one"], "one"),
+        ];
+        for &(lines, text) in cases {
+            assert_eq!(extract(lines), text, "{:?}", lines);
+        }
+    }
+}
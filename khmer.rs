@@ -0,0 +1,400 @@
+use super::diagnose::Role;
+use super::myanmar::{
+    match_chars, match_either, match_one, match_optional, match_optional_seq, match_repeat_upto,
+    match_seq,
+};
+use super::Syllable;
+
+// Same practical limit as Myanmar, see myanmar.rs
+const MAX_CLUSTER_LEN: usize = 31;
+const MAX_REPEAT: usize = MAX_CLUSTER_LEN / 3;
+
+#[allow(unused)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum ShapingClass {
+    Bindu,
+    Visarga,
+    Avagraha,
+    PureKiller,
+    ConsonantKiller,
+    SyllableModifier,
+    RegisterShifter,
+    Consonant,
+    ConsonantSucceedingRepha,
+    VowelIndependent,
+    VowelDependent,
+    InvisibleStacker,
+    Number,
+    Placeholder,
+    Joiner,
+    NonJoiner,
+    DottedCircle,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Copy, Clone, Debug)]
+enum MarkPlacementSubclass {
+    TopPosition,
+    RightPosition,
+    BottomPosition,
+    LeftPosition,
+    LeftAndRightPosition,
+    TopAndLeftPosition,
+    TopLeftAndRightPosition,
+}
+
+fn shaping_class(ch: char) -> Option<ShapingClass> {
+    let (shaping, _) = khmer_character(ch);
+    shaping
+}
+
+// _C_ | _Ra_ | _V_
+fn consonant_or_vowel(ch: char) -> bool {
+    matches!(
+        shaping_class(ch),
+        Some(ShapingClass::Consonant | ShapingClass::VowelIndependent)
+    )
+}
+
+// _PLACEHOLDER_
+//
+// Khmer digits are placeholders too, so that marks applied to them are displayed without a
+// dotted circle.
+fn placeholder(ch: char) -> bool {
+    matches!(
+        shaping_class(ch),
+        Some(ShapingClass::Placeholder | ShapingClass::Number)
+    )
+}
+
+fn dotted_circle(ch: char) -> bool {
+    shaping_class(ch) == Some(ShapingClass::DottedCircle)
+}
+
+// _Coeng_
+fn coeng(ch: char) -> bool {
+    shaping_class(ch) == Some(ShapingClass::InvisibleStacker)
+}
+
+fn joiner(ch: char) -> bool {
+    matches!(
+        shaping_class(ch),
+        Some(ShapingClass::Joiner | ShapingClass::NonJoiner)
+    )
+}
+
+// "Muusikatoan" | "Triisap" | "Robat"
+fn robatic(ch: char) -> bool {
+    matches!(ch, '\u{17C9}' | '\u{17CA}' | '\u{17CC}')
+}
+
+// "Nikahit" | "Bantoc" | "Toandakhiat" | "Kakabat" | "Ahsda" | "Samyok Sannya" | "Viriam"
+fn xgroup(ch: char) -> bool {
+    matches!(ch, '\u{17C6}' | '\u{17CB}' | '\u{17CD}'..='\u{17D1}')
+}
+
+// "Reahmuk" | "Yuukaleapintu" | "Bathamasat" | "Atthacan"
+fn ygroup(ch: char) -> bool {
+    matches!(ch, '\u{17C7}' | '\u{17C8}' | '\u{17D3}' | '\u{17DD}')
+}
+
+fn matra(ch: char, f: impl Fn(MarkPlacementSubclass) -> bool) -> bool {
+    match khmer_character(ch) {
+        (Some(ShapingClass::VowelDependent), Some(pos)) => f(pos),
+        _ => false,
+    }
+}
+
+// _VPre_
+fn matra_pre(ch: char) -> bool {
+    matra(ch, |pos| matches!(pos, MarkPlacementSubclass::LeftPosition))
+}
+
+// _VAbv_
+//
+// Split vowels are not decomposed, so they take the slot of their last component.
+fn matra_above(ch: char) -> bool {
+    matra(ch, |pos| {
+        matches!(
+            pos,
+            MarkPlacementSubclass::TopPosition | MarkPlacementSubclass::TopAndLeftPosition
+        )
+    })
+}
+
+// _VBlw_
+fn matra_below(ch: char) -> bool {
+    matra(ch, |pos| {
+        matches!(pos, MarkPlacementSubclass::BottomPosition)
+    })
+}
+
+// _VPst_
+fn matra_post(ch: char) -> bool {
+    matra(ch, |pos| {
+        matches!(
+            pos,
+            MarkPlacementSubclass::RightPosition
+                | MarkPlacementSubclass::LeftAndRightPosition
+                | MarkPlacementSubclass::TopLeftAndRightPosition
+        )
+    })
+}
+
+fn match_unit(_cs: &[char]) -> Option<usize> {
+    Some(0)
+}
+
+fn match_z(cs: &[char]) -> Option<usize> {
+    match_one(joiner)(cs)
+}
+
+// c = (_C_ | _Ra_ | _V_)
+fn match_c(cs: &[char]) -> Option<usize> {
+    match_one(consonant_or_vowel)(cs)
+}
+
+// cn = c ((_ZWJ_ | _ZWNJ_)? _Robatic_)?
+fn match_cn(cs: &[char]) -> Option<usize> {
+    match_seq(
+        match_c,
+        match_optional(match_optional_seq(match_z, match_one(robatic))),
+    )(cs)
+}
+
+// xgroup = (joiner* _Xgroup_)*, followed by `g`
+fn match_xgroup_then(g: impl Fn(&[char]) -> Option<usize>) -> impl Fn(&[char]) -> Option<usize> {
+    match_repeat_upto(
+        MAX_REPEAT,
+        match_repeat_upto(MAX_REPEAT, match_z, match_one(xgroup)),
+        g,
+    )
+}
+
+// matra_group = _VPre_? xgroup _VBlw_? xgroup (joiner? _VAbv_)? xgroup _VPst_?, followed by `g`
+fn match_matra_group_then(
+    g: impl Fn(&[char]) -> Option<usize>,
+) -> impl Fn(&[char]) -> Option<usize> {
+    match_optional_seq(
+        match_one(matra_pre),
+        match_xgroup_then(match_optional_seq(
+            match_one(matra_below),
+            match_xgroup_then(match_optional_seq(
+                match_optional_seq(match_z, match_one(matra_above)),
+                match_xgroup_then(match_optional_seq(match_one(matra_post), g)),
+            )),
+        )),
+    )
+}
+
+// syllable_tail = xgroup matra_group xgroup (_Coeng_ c)? ygroup
+fn match_syllable_tail(cs: &[char]) -> Option<usize> {
+    match_xgroup_then(match_matra_group_then(match_xgroup_then(
+        match_optional_seq(
+            match_seq(match_one(coeng), match_c),
+            match_repeat_upto(MAX_REPEAT, match_one(ygroup), match_unit),
+        ),
+    )))(cs)
+}
+
+// broken_cluster = _Robatic_? (_Coeng_ cn)* (_Coeng_ | syllable_tail)
+fn match_broken_cluster(cs: &[char]) -> Option<usize> {
+    match_optional_seq(
+        match_one(robatic),
+        match_repeat_upto(
+            MAX_REPEAT,
+            match_seq(match_one(coeng), match_cn),
+            match_either(match_one(coeng), match_syllable_tail),
+        ),
+    )(cs)
+}
+
+// consonant_syllable = (cn | _PLACEHOLDER_ | _DOTTEDCIRCLE_) broken_cluster
+fn match_consonant_syllable(cs: &[char]) -> Option<usize> {
+    match_seq(
+        match_either(
+            match_cn,
+            match_either(match_one(placeholder), match_one(dotted_circle)),
+        ),
+        match_broken_cluster,
+    )(cs)
+}
+
+// Punctuation and symbols from the Khmer blocks form a cluster on their own
+fn standalone(ch: char) -> bool {
+    matches!(ch, '\u{1780}'..='\u{17FF}' | '\u{19E0}'..='\u{19FF}') && shaping_class(ch).is_none()
+}
+
+pub fn match_syllable(cs: &str) -> Option<(usize, Syllable)> {
+    match_chars(cs, |cs| {
+        if let Some(len) = match_consonant_syllable(cs) {
+            return Some((len, Syllable::Consonant));
+        }
+        match match_broken_cluster(cs) {
+            Some(len) if len > 0 => Some((len, Syllable::Broken)),
+            _ => match_one(standalone)(cs).map(|len| (len, Syllable::Symbol)),
+        }
+    })
+}
+
+/// The shaping class of `ch` for `super::pattern`, with its placement if it is a mark.
+pub(crate) fn class_name(ch: char) -> String {
+    let (class, placement) = khmer_character(ch);
+    super::format_class(ch, class, placement)
+}
+
+/// What `ch` does in a syllable, for `super::diagnose`.
+pub(crate) fn role(ch: char) -> Option<Role> {
+    use self::ShapingClass::*;
+
+    Some(match khmer_character(ch).0? {
+        Consonant | ConsonantSucceedingRepha | Placeholder | DottedCircle => Role::Base,
+        VowelIndependent => Role::VowelIndependent,
+        InvisibleStacker | PureKiller => Role::Virama,
+        VowelDependent => Role::VowelDependent,
+        Bindu | Visarga | ConsonantKiller | SyllableModifier | RegisterShifter => Role::Mark,
+        Joiner | NonJoiner => Role::Joiner,
+        Avagraha | Number => Role::Other,
+    })
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Khmer character tables
+/////////////////////////////////////////////////////////////////////////////
+
+fn khmer_character(ch: char) -> (Option<ShapingClass>, Option<MarkPlacementSubclass>) {
+    use self::MarkPlacementSubclass::*;
+    use self::ShapingClass::*;
+
+    match ch as u32 {
+        // Khmer character table
+        0x1780 => (Some(Consonant), None),        // ក Ka
+        0x1781 => (Some(Consonant), None),        // ខ Kha
+        0x1782 => (Some(Consonant), None),        // គ Ko
+        0x1783 => (Some(Consonant), None),        // ឃ Kho
+        0x1784 => (Some(Consonant), None),        // ង Ngo
+        0x1785 => (Some(Consonant), None),        // ច Ca
+        0x1786 => (Some(Consonant), None),        // ឆ Cha
+        0x1787 => (Some(Consonant), None),        // ជ Co
+        0x1788 => (Some(Consonant), None),        // ឈ Cho
+        0x1789 => (Some(Consonant), None),        // ញ Nyo
+        0x178A => (Some(Consonant), None),        // ដ Da
+        0x178B => (Some(Consonant), None),        // ឋ Ttha
+        0x178C => (Some(Consonant), None),        // ឌ Do
+        0x178D => (Some(Consonant), None),        // ឍ Ttho
+        0x178E => (Some(Consonant), None),        // ណ Nno
+        0x178F => (Some(Consonant), None),        // ត Ta
+        0x1790 => (Some(Consonant), None),        // ថ Tha
+        0x1791 => (Some(Consonant), None),        // ទ To
+        0x1792 => (Some(Consonant), None),        // ធ Tho
+        0x1793 => (Some(Consonant), None),        // ន No
+        0x1794 => (Some(Consonant), None),        // ប Ba
+        0x1795 => (Some(Consonant), None),        // ផ Pha
+        0x1796 => (Some(Consonant), None),        // ព Po
+        0x1797 => (Some(Consonant), None),        // ភ Pho
+        0x1798 => (Some(Consonant), None),        // ម Mo
+        0x1799 => (Some(Consonant), None),        // យ Yo
+        0x179A => (Some(Consonant), None),        // រ Ro
+        0x179B => (Some(Consonant), None),        // ល Lo
+        0x179C => (Some(Consonant), None),        // វ Vo
+        0x179D => (Some(Consonant), None),        // ឝ Sha
+        0x179E => (Some(Consonant), None),        // ឞ Sso
+        0x179F => (Some(Consonant), None),        // ស Sa
+        0x17A0 => (Some(Consonant), None),        // ហ Ha
+        0x17A1 => (Some(Consonant), None),        // ឡ La
+        0x17A2 => (Some(Consonant), None),        // អ Qa
+        0x17A3 => (Some(VowelIndependent), None), // ឣ Qaq
+        0x17A4 => (Some(VowelIndependent), None), // ឤ Qaa
+        0x17A5 => (Some(VowelIndependent), None), // ឥ Qi
+        0x17A6 => (Some(VowelIndependent), None), // ឦ Qii
+        0x17A7 => (Some(VowelIndependent), None), // ឧ Qu
+        0x17A8 => (Some(VowelIndependent), None), // ឨ Quk
+        0x17A9 => (Some(VowelIndependent), None), // ឩ Quu
+        0x17AA => (Some(VowelIndependent), None), // ឪ Quuv
+        0x17AB => (Some(VowelIndependent), None), // ឫ Ry
+        0x17AC => (Some(VowelIndependent), None), // ឬ Ryy
+        0x17AD => (Some(VowelIndependent), None), // ឭ Ly
+        0x17AE => (Some(VowelIndependent), None), // ឮ Lyy
+        0x17AF => (Some(VowelIndependent), None), // ឯ Qe
+        0x17B0 => (Some(VowelIndependent), None), // ឰ Qai
+        0x17B1 => (Some(VowelIndependent), None), // ឱ Qoo Type One
+        0x17B2 => (Some(VowelIndependent), None), // ឲ Qoo Type Two
+        0x17B3 => (Some(VowelIndependent), None), // ឳ Qau
+        0x17B4 => (None, None),                   // ឴ Inherent Aq
+        0x17B5 => (None, None),                   // ឵ Inherent Aa
+        0x17B6 => (Some(VowelDependent), Some(RightPosition)), // ា Sign Aa
+        0x17B7 => (Some(VowelDependent), Some(TopPosition)), // ិ Sign I
+        0x17B8 => (Some(VowelDependent), Some(TopPosition)), // ី Sign Ii
+        0x17B9 => (Some(VowelDependent), Some(TopPosition)), // ឹ Sign Y
+        0x17BA => (Some(VowelDependent), Some(TopPosition)), // ឺ Sign Yy
+        0x17BB => (Some(VowelDependent), Some(BottomPosition)), // ុ Sign U
+        0x17BC => (Some(VowelDependent), Some(BottomPosition)), // ូ Sign Uu
+        0x17BD => (Some(VowelDependent), Some(BottomPosition)), // ួ Sign Ua
+        0x17BE => (Some(VowelDependent), Some(TopAndLeftPosition)), // ើ Sign Oe
+        0x17BF => (Some(VowelDependent), Some(TopLeftAndRightPosition)), // ឿ Sign Ya
+        0x17C0 => (Some(VowelDependent), Some(LeftAndRightPosition)), // ៀ Sign Ie
+        0x17C1 => (Some(VowelDependent), Some(LeftPosition)), // េ Sign E
+        0x17C2 => (Some(VowelDependent), Some(LeftPosition)), // ែ Sign Ae
+        0x17C3 => (Some(VowelDependent), Some(LeftPosition)), // ៃ Sign Ai
+        0x17C4 => (Some(VowelDependent), Some(LeftAndRightPosition)), // ោ Sign Oo
+        0x17C5 => (Some(VowelDependent), Some(LeftAndRightPosition)), // ៅ Sign Au
+        0x17C6 => (Some(Bindu), Some(TopPosition)), // ំ Sign Nikahit
+        0x17C7 => (Some(Visarga), Some(RightPosition)), // ះ Sign Reahmuk
+        0x17C8 => (Some(SyllableModifier), Some(RightPosition)), // ៈ Sign Yuukaleapintu
+        0x17C9 => (Some(RegisterShifter), Some(TopPosition)), // ៉ Sign Muusikatoan
+        0x17CA => (Some(RegisterShifter), Some(TopPosition)), // ៊ Sign Triisap
+        0x17CB => (Some(SyllableModifier), Some(TopPosition)), // ់ Sign Bantoc
+        0x17CC => (Some(ConsonantSucceedingRepha), Some(TopPosition)), // ៌ Sign Robat
+        0x17CD => (Some(ConsonantKiller), Some(TopPosition)), // ៍ Sign Toandakhiat
+        0x17CE => (Some(SyllableModifier), Some(TopPosition)), // ៎ Sign Kakabat
+        0x17CF => (Some(SyllableModifier), Some(TopPosition)), // ៏ Sign Ahsda
+        0x17D0 => (Some(SyllableModifier), Some(TopPosition)), // ័ Sign Samyok Sannya
+        0x17D1 => (Some(PureKiller), Some(TopPosition)), // ៑ Sign Viriam
+        0x17D2 => (Some(InvisibleStacker), None), // ្ Sign Coeng
+        0x17D3 => (Some(SyllableModifier), Some(TopPosition)), // ៓ Sign Bathamasat
+        0x17D4 => (None, None),                   // ។ Sign Khan
+        0x17D5 => (None, None),                   // ៕ Sign Bariyoosan
+        0x17D6 => (None, None),                   // ៖ Sign Camnuc Pii Kuuh
+        0x17D7 => (None, None),                   // ៗ Sign Lek Too
+        0x17D8 => (None, None),                   // ៘ Sign Beyyal
+        0x17D9 => (Some(Placeholder), None),      // ៙ Sign Phnaek Muan
+        0x17DA => (None, None),                   // ៚ Sign Koomuut
+        0x17DB => (None, None),                   // ៛ Currency Symbol Riel
+        0x17DC => (Some(Avagraha), None),         // ៜ Sign Avakrahasanya
+        0x17DD => (Some(SyllableModifier), Some(TopPosition)), // ៝ Sign Atthacan
+        0x17E0 => (Some(Number), None),           // ០ Digit Zero
+        0x17E1 => (Some(Number), None),           // ១ Digit One
+        0x17E2 => (Some(Number), None),           // ២ Digit Two
+        0x17E3 => (Some(Number), None),           // ៣ Digit Three
+        0x17E4 => (Some(Number), None),           // ៤ Digit Four
+        0x17E5 => (Some(Number), None),           // ៥ Digit Five
+        0x17E6 => (Some(Number), None),           // ៦ Digit Six
+        0x17E7 => (Some(Number), None),           // ៧ Digit Seven
+        0x17E8 => (Some(Number), None),           // ៨ Digit Eight
+        0x17E9 => (Some(Number), None),           // ៩ Digit Nine
+        0x17F0 => (None, None),                   // ៰ Symbol Lek Attak Son
+        0x17F1 => (None, None),                   // ៱ Symbol Lek Attak Muoy
+        0x17F2 => (None, None),                   // ៲ Symbol Lek Attak Pii
+        0x17F3 => (None, None),                   // ៳ Symbol Lek Attak Bei
+        0x17F4 => (None, None),                   // ៴ Symbol Lek Attak Buon
+        0x17F5 => (None, None),                   // ៵ Symbol Lek Attak Pram
+        0x17F6 => (None, None),                   // ៶ Symbol Lek Attak Pram-muoy
+        0x17F7 => (None, None),                   // ៷ Symbol Lek Attak Pram-pii
+        0x17F8 => (None, None),                   // ៸ Symbol Lek Attak Pram-bei
+        0x17F9 => (None, None),                   // ៹ Symbol Lek Attak Pram-buon
+
+        // Miscellaneous character table
+        0x00A0 => (Some(Placeholder), None),  //   No-break space
+        0x00D7 => (Some(Placeholder), None),  // × Multiplication sign
+        0x200C => (Some(NonJoiner), None),    // ‌ Zero-width non-joiner
+        0x200D => (Some(Joiner), None),       // ‍ Zero-width joiner
+        0x2010 => (Some(Placeholder), None),  // ‐ Hyphen
+        0x2011 => (Some(Placeholder), None),  // ‑ No-break hyphen
+        0x2012 => (Some(Placeholder), None),  // ‒ Figure dash
+        0x2013 => (Some(Placeholder), None),  // – En dash
+        0x2014 => (Some(Placeholder), None),  // — Em dash
+        0x25CC => (Some(DottedCircle), None), // ◌ Dotted circle
+
+        _ => (None, None),
+    }
+}
@@ -0,0 +1,317 @@
+//! Telling text in the Krutidev font encoding from English, and converting it to Devanagari.
+//!
+//! Krutidev fonts draw Devanagari glyphs in place of ASCII and Latin-1 characters, so the
+//! text looks like Latin noise to anything but the font. It is stored in visual order, with
+//! the vowel sign I before its consonant and the reph after its syllable.
+
+/// The words that are most common in Hindi text in Krutidev, such as "gS" for है and "dk"
+/// for का.
+const COMMON_WORDS: &[&str] = &[
+    "dk", "ds", "dh", "esa", "gS", "gSa", "vkSj", "ls", "ij", "dks", ";g", "Fkk", "Fks", "Fkh",
+    "fd", "Hkh", "rks", "ugha", ",d", "tks", "bl", "mu", "fy,",
+];
+
+/// Whether `text` looks like Krutidev rather than English or another Latin script.
+///
+/// Either it has at least two of the most common Krutidev words, or one in ten of its
+/// letters is "k", the vowel sign AA, and it has a word with a capital letter after a small
+/// one, as Krutidev has for half forms and some vowel signs.
+pub fn is_krutidev(text: &str) -> bool {
+    let letters = text.chars().filter(char::is_ascii_alphabetic).count();
+    if letters < 8 {
+        return false;
+    }
+    let aa = text.chars().filter(|&ch| ch == 'k').count();
+    let mut common = 0;
+    let mut mixed = false;
+    for word in text.split_whitespace() {
+        if COMMON_WORDS.contains(&word) {
+            common += 1;
+        }
+        let chars: Vec<char> = word.chars().collect();
+        mixed |= chars
+            .windows(2)
+            .any(|w| w[0].is_ascii_lowercase() && w[1].is_ascii_uppercase());
+    }
+    common >= 2 || (aa * 10 >= letters && mixed)
+}
+
+/// A piece of converted text, before the vowel sign I and the reph are moved.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Piece {
+    Char(char),
+    /// The vowel sign I, which goes after the consonant cluster that follows it
+    VowelSignI,
+    /// The reph, which goes before the consonant cluster that it follows
+    Reph,
+}
+
+/// The Devanagari for a sequence of one to three Krutidev characters.
+///
+/// Half forms end in a virama, and are full forms when followed by "k".
+fn lookup(s: &str) -> Option<&'static str> {
+    Some(match s {
+        // independent vowels
+        "v" => "\u{0905}",
+        "vk" => "\u{0906}",
+        "vks" => "\u{0913}",
+        "vkS" => "\u{0914}",
+        "v\u{201A}" => "\u{0911}",
+        "b" => "\u{0907}",
+        "bZ" | "\u{00C3}" => "\u{0908}",
+        "m" => "\u{0909}",
+        "\u{00C5}" => "\u{090A}",
+        "_" => "\u{090B}",
+        "," => "\u{090F}",
+        ",s" => "\u{0910}",
+        // consonants
+        "d" => "\u{0915}",
+        "D" => "\u{0915}\u{094D}",
+        "[" => "\u{0916}\u{094D}",
+        "x" => "\u{0917}",
+        "X" => "\u{0917}\u{094D}",
+        "\u{00C4}" => "\u{0918}",
+        "?" => "\u{0918}\u{094D}",
+        "\u{00B3}" => "\u{0919}",
+        "p" => "\u{091A}",
+        "P" => "\u{091A}\u{094D}",
+        "N" => "\u{091B}",
+        "t" => "\u{091C}",
+        "T" => "\u{091C}\u{094D}",
+        ">" => "\u{091D}",
+        "\u{00F7}" => "\u{091D}\u{094D}",
+        "\u{00A5}" => "\u{091E}",
+        "V" => "\u{091F}",
+        "B" => "\u{0920}",
+        "M" => "\u{0921}",
+        "<" => "\u{0922}",
+        "." => "\u{0923}\u{094D}",
+        "r" => "\u{0924}",
+        "R" => "\u{0924}\u{094D}",
+        "F" => "\u{0925}\u{094D}",
+        "n" => "\u{0926}",
+        "/" | "\u{00E8}" => "\u{0927}\u{094D}",
+        "u" => "\u{0928}",
+        "U" => "\u{0928}\u{094D}",
+        "i" => "\u{092A}",
+        "I" => "\u{092A}\u{094D}",
+        "Q" => "\u{092B}",
+        "\u{00B6}" => "\u{092B}\u{094D}",
+        "c" => "\u{092C}",
+        "C" => "\u{092C}\u{094D}",
+        "H" => "\u{092D}\u{094D}",
+        "e" => "\u{092E}",
+        "E" => "\u{092E}\u{094D}",
+        ";" => "\u{092F}",
+        "\u{00B8}" => "\u{092F}\u{094D}",
+        "j" => "\u{0930}",
+        "y" => "\u{0932}",
+        "Y" => "\u{0932}\u{094D}",
+        "G" => "\u{0933}",
+        "o" => "\u{0935}",
+        "O" => "\u{0935}\u{094D}",
+        "'" => "\u{0936}\u{094D}",
+        "\"" => "\u{0937}\u{094D}",
+        "l" => "\u{0938}",
+        "L" => "\u{0938}\u{094D}",
+        "g" => "\u{0939}",
+        "\u{00BA}" => "\u{0939}\u{094D}",
+        // conjuncts
+        "K" => "\u{091C}\u{094D}\u{091E}",
+        "J" => "\u{0936}\u{094D}\u{0930}",
+        "{" => "\u{0915}\u{094D}\u{0937}\u{094D}",
+        "=" => "\u{0924}\u{094D}\u{0930}",
+        "\u{00AB}" => "\u{0924}\u{094D}\u{0930}\u{094D}",
+        "|" => "\u{0926}\u{094D}\u{092F}",
+        "}" => "\u{0926}\u{094D}\u{0935}",
+        ")" => "\u{0926}\u{094D}\u{0927}",
+        "\u{00D8}" => "\u{0915}\u{094D}\u{0930}",
+        "\u{00E7}" | "\u{00C1}" => "\u{092A}\u{094D}\u{0930}",
+        "\u{00DD}" => "\u{092B}\u{094D}\u{0930}",
+        "\u{00E6}" => "\u{0926}\u{094D}\u{0930}",
+        "\u{00ED}" | "\u{00CC}" => "\u{0926}\u{094D}\u{0926}",
+        "\u{00EA}" | "\u{00CD}" => "\u{091F}\u{094D}\u{091F}",
+        "\u{00EB}" | "\u{00CE}" => "\u{091F}\u{094D}\u{0920}",
+        "\u{00EC}" | "\u{00CF}" => "\u{0921}\u{094D}\u{0921}",
+        "\u{00EF}" | "\u{00D4}" => "\u{0921}\u{094D}\u{0922}",
+        "\u{00E9}" => "\u{0928}\u{094D}\u{0928}",
+        "\u{2122}" => "\u{0928}\u{094D}\u{0928}\u{094D}",
+        "\u{00D9}" => "\u{0924}\u{094D}\u{0924}\u{094D}",
+        "\u{00F4}" => "\u{0915}\u{094D}\u{0915}",
+        "\u{00E0}" => "\u{0939}\u{094D}\u{0928}",
+        "\u{00E1}" => "\u{0939}\u{094D}\u{092F}",
+        "\u{00E2}" => "\u{0939}\u{0943}",
+        "\u{00E3}" => "\u{0939}\u{094D}\u{092E}",
+        "#" => "\u{0930}\u{0941}",
+        ":" => "\u{0930}\u{0942}",
+        "\u{2013}" => "\u{0926}\u{0943}",
+        "\u{2014}" | "\u{00D1}" => "\u{0915}\u{0943}",
+        // vowel signs and other marks
+        "k" => "\u{093E}",
+        "h" => "\u{0940}",
+        "q" => "\u{0941}",
+        "w" => "\u{0942}",
+        "`" => "\u{0943}",
+        "s" => "\u{0947}",
+        "S" => "\u{0948}",
+        "ks" => "\u{094B}",
+        "kS" => "\u{094C}",
+        "\u{201A}" => "\u{0949}",
+        "W" => "\u{0945}",
+        "\u{00C8}" => "\u{0940}\u{0902}",
+        "a" => "\u{0902}",
+        "\u{00A1}" => "\u{0901}",
+        "%" => "\u{0903}",
+        "~" => "\u{094D}",
+        "+" => "\u{093C}",
+        "z" => "\u{094D}\u{0930}",
+        "\u{00D3}" => "\u{094D}\u{092F}",
+        // punctuation and digits
+        "A" => "\u{0964}",
+        "\u{2022}" | "\u{00B7}" => "\u{093D}",
+        "\u{0152}" | "\u{00F1}" => "\u{0970}",
+        "&" => "-",
+        "-" => ".",
+        "]" => ",",
+        "@" => "/",
+        "\u{00BE}" => "=",
+        "\u{00BC}" => "(",
+        "\u{00BD}" => ")",
+        "^" => "\u{2018}",
+        "*" => "\u{2019}",
+        "\u{00DE}" => "\u{201C}",
+        "\u{00DF}" => "\u{201D}",
+        "\u{00E5}" => "\u{0966}",
+        "\u{0192}" => "\u{0967}",
+        "\u{201E}" => "\u{0968}",
+        "\u{2026}" => "\u{0969}",
+        "\u{2020}" => "\u{096A}",
+        "\u{2021}" => "\u{096B}",
+        "\u{02C6}" => "\u{096C}",
+        "\u{2030}" => "\u{096D}",
+        "\u{0160}" => "\u{096E}",
+        "\u{2039}" => "\u{096F}",
+        _ => return None,
+    })
+}
+
+fn consonant(piece: Piece) -> bool {
+    matches!(
+        piece,
+        Piece::Char('\u{0915}'..='\u{0939}' | '\u{0958}'..='\u{095F}')
+    )
+}
+
+/// A vowel sign, nukta or bindu, which the reph goes before along with its consonant.
+fn mark(piece: Piece) -> bool {
+    matches!(
+        piece,
+        Piece::Char('\u{0900}'..='\u{0903}' | '\u{093A}'..='\u{094C}')
+    )
+}
+
+const VIRAMA: Piece = Piece::Char('\u{094D}');
+const NUKTA: Piece = Piece::Char('\u{093C}');
+
+/// Converts Krutidev text to Devanagari.
+///
+/// Characters that have no mapping, such as ASCII digits and spaces, are left alone.
+pub fn krutidev_to_unicode(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut pieces = Vec::with_capacity(chars.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            'f' => {
+                pieces.push(Piece::VowelSignI);
+                i += 1;
+                continue;
+            }
+            'Z' => {
+                pieces.push(Piece::Reph);
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        let found = (1..=3).rev().find_map(|len| {
+            let s: String = chars.get(i..i + len)?.iter().collect();
+            lookup(&s).map(|unicode| (len, unicode))
+        });
+        match found {
+            Some((len, unicode)) => {
+                i += len;
+                match unicode.strip_suffix('\u{094D}') {
+                    // a half form followed by the vowel sign AA is a full form
+                    Some(full) if chars.get(i) == Some(&'k') => {
+                        pieces.extend(full.chars().map(Piece::Char));
+                        i += 1;
+                    }
+                    _ => pieces.extend(unicode.chars().map(Piece::Char)),
+                }
+            }
+            None => {
+                pieces.push(Piece::Char(chars[i]));
+                i += 1;
+            }
+        }
+    }
+    move_vowel_sign_i(&mut pieces);
+    move_reph(&mut pieces);
+    pieces
+        .iter()
+        .map(|&piece| match piece {
+            Piece::Char(ch) => ch,
+            Piece::VowelSignI => '\u{093F}',
+            Piece::Reph => unreachable!(),
+        })
+        .collect()
+}
+
+/// Moves each vowel sign I after the consonant cluster that follows it.
+fn move_vowel_sign_i(pieces: &mut Vec<Piece>) {
+    let mut i = 0;
+    while i < pieces.len() {
+        if pieces[i] != Piece::VowelSignI || !pieces.get(i + 1).is_some_and(|&p| consonant(p)) {
+            i += 1;
+            continue;
+        }
+        let mut end = i + 1;
+        loop {
+            if pieces.get(end + 1) == Some(&NUKTA) {
+                end += 1;
+            }
+            if pieces.get(end + 1) == Some(&VIRAMA)
+                && pieces.get(end + 2).is_some_and(|&p| consonant(p))
+            {
+                end += 2;
+            } else {
+                break;
+            }
+        }
+        pieces.remove(i);
+        pieces.insert(end, Piece::Char('\u{093F}'));
+        i = end + 1;
+    }
+}
+
+/// Replaces each reph with Ra and virama before the consonant cluster that it follows.
+fn move_reph(pieces: &mut Vec<Piece>) {
+    while let Some(k) = pieces.iter().position(|&p| p == Piece::Reph) {
+        pieces.remove(k);
+        let mut start = k;
+        while start > 0 && mark(pieces[start - 1]) {
+            start -= 1;
+        }
+        if start > 0 && consonant(pieces[start - 1]) {
+            start -= 1;
+            while start >= 2 && pieces[start - 1] == VIRAMA && consonant(pieces[start - 2]) {
+                start -= 2;
+            }
+        } else {
+            start = k;
+        }
+        pieces.insert(start, Piece::Char('\u{0930}'));
+        pieces.insert(start + 1, VIRAMA);
+    }
+}
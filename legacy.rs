@@ -0,0 +1,115 @@
+//! Finding lines of text that are in a legacy font encoding rather than Unicode.
+//!
+//! News sites and dictionaries still have text in 8-bit font encodings, which look like
+//! Latin noise, and in Unicode that was mapped from them character by character, which
+//! fails to parse. Lines are flagged either by a known encoding, or by how many of their
+//! syllables fail compared with ordinary text.
+
+use super::diagnose::in_script;
+use super::{krutidev, segment, zawgyi, Script, Syllable};
+
+/// Why a line of text looks like it is not in Unicode.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Legacy {
+    /// Myanmar text in the Zawgyi font encoding
+    Zawgyi,
+    /// Devanagari in the Krutidev font encoding, which looks like ASCII
+    Krutidev,
+    /// Mostly Latin-1 letters, as in the Shree-Lipi and other 8-bit font encodings
+    FontEncoding,
+    /// Text in the script with many more failures than usual, such as text mapped from a
+    /// font encoding character by character
+    Misencoded,
+}
+
+/// Fewest failures for a line to be misencoded
+const MIN_FAILURES: usize = 3;
+
+/// Fewest Latin-1 letters for a line to be in a font encoding
+const MIN_LATIN1_LETTERS: usize = 5;
+
+impl Legacy {
+    pub const ALL: [Legacy; 4] = [
+        Legacy::Zawgyi,
+        Legacy::Krutidev,
+        Legacy::FontEncoding,
+        Legacy::Misencoded,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Legacy::Zawgyi => "zawgyi",
+            Legacy::Krutidev => "krutidev",
+            Legacy::FontEncoding => "font-encoding",
+            Legacy::Misencoded => "misencoded",
+        }
+    }
+
+    /// Whether `detect_legacy` looks for this encoding in text of `script`.
+    pub fn applies_to(self, script: Script) -> bool {
+        match self {
+            Legacy::Zawgyi => script == Script::Myanmar,
+            Legacy::Krutidev => script == Script::Devanagari,
+            Legacy::FontEncoding | Legacy::Misencoded => true,
+        }
+    }
+
+    /// Whether `convert` can convert text in this encoding.
+    pub fn convertible(self) -> bool {
+        matches!(self, Legacy::Zawgyi | Legacy::Krutidev)
+    }
+
+    /// Converts `text` to Unicode, if there is a converter for this encoding.
+    pub fn convert(self, text: &str) -> Option<String> {
+        match self {
+            Legacy::Zawgyi => Some(zawgyi::zawgyi_to_unicode(text)),
+            Legacy::Krutidev => Some(krutidev::krutidev_to_unicode(text)),
+            Legacy::FontEncoding | Legacy::Misencoded => None,
+        }
+    }
+}
+
+/// The number of failures in `text`, bad clusters and broken syllables, and the number of
+/// clusters in the script.
+pub fn failures(text: &str, script: Script) -> (usize, usize) {
+    let (mut failures, mut clusters) = (0, 0);
+    for cluster in segment(text, script) {
+        if !cluster.text.chars().any(|ch| in_script(ch, script)) {
+            continue;
+        }
+        clusters += 1;
+        // Myanmar punctuation is matched by the broken syllable rule
+        let broken = cluster.kind == Syllable::Broken && script != Script::Myanmar;
+        if cluster.broken || broken {
+            failures += 1;
+        }
+    }
+    (failures, clusters)
+}
+
+/// Finds whether a line of text in `script` is in a legacy encoding.
+///
+/// Zawgyi is only looked for in Myanmar and Krutidev in Devanagari. A line is in some other
+/// font encoding if a quarter of its letters are Latin-1 letters, and misencoded if a
+/// quarter of its clusters in the script fail, and at least `MIN_FAILURES`.
+pub fn detect_legacy(text: &str, script: Script) -> Option<Legacy> {
+    if Legacy::Zawgyi.applies_to(script) && zawgyi::zawgyi_score(text).is_zawgyi() {
+        return Some(Legacy::Zawgyi);
+    }
+    if Legacy::Krutidev.applies_to(script) && krutidev::is_krutidev(text) {
+        return Some(Legacy::Krutidev);
+    }
+    let letters = text.chars().filter(|ch| ch.is_alphabetic()).count();
+    let latin1 = text
+        .chars()
+        .filter(|&ch| ('\u{00C0}'..='\u{00FF}').contains(&ch) && ch.is_alphabetic())
+        .count();
+    if latin1 >= MIN_LATIN1_LETTERS && latin1 * 4 >= letters {
+        return Some(Legacy::FontEncoding);
+    }
+    let (failures, clusters) = failures(text, script);
+    if failures >= MIN_FAILURES && failures * 4 >= clusters {
+        return Some(Legacy::Misencoded);
+    }
+    None
+}
@@ -0,0 +1,362 @@
+//! Syllable (cluster) segmentation for Indic, Myanmar, Khmer and Universal Shaping Engine
+//! text, following the grammars in the OpenType shaping documents.
+
+extern crate unicode_normalization;
+
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::ops::Range;
+
+use unicode_normalization::{is_nfc, is_nfd, UnicodeNormalization};
+
+pub mod dfa;
+pub mod diagnose;
+pub mod indic;
+pub mod khmer;
+pub mod krutidev;
+pub mod legacy;
+pub mod myanmar;
+pub mod reorder;
+pub mod universal;
+pub mod zawgyi;
+
+pub use diagnose::{diagnose, Cause, Origin};
+pub use indic::{indic_character, indic_name, MarkPlacementSubclass, ShapingClass};
+pub use krutidev::{is_krutidev, krutidev_to_unicode};
+pub use legacy::{detect_legacy, Legacy};
+pub use reorder::{reorder, reorders, Position};
+pub use zawgyi::{zawgyi_score, zawgyi_to_unicode, ZawgyiScore};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+    Devanagari,
+    Bengali,
+    Tamil,
+    Telugu,
+    Gujarati,
+    Gurmukhi,
+    Oriya,
+    Malayalam,
+    Myanmar,
+    Kannada,
+    Sinhala,
+    Khmer,
+    Balinese,
+    Javanese,
+    TaiTham,
+    Chakma,
+    Sharada,
+    Tirhuta,
+    Kawi,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Syllable {
+    Consonant,
+    Vowel,
+    Standalone,
+    Symbol,
+    Broken,
+}
+
+impl Syllable {
+    pub const ALL: [Syllable; 5] = [
+        Syllable::Consonant,
+        Syllable::Vowel,
+        Syllable::Standalone,
+        Syllable::Symbol,
+        Syllable::Broken,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Syllable::Consonant => "consonant",
+            Syllable::Vowel => "vowel",
+            Syllable::Standalone => "standalone",
+            Syllable::Symbol => "symbol",
+            Syllable::Broken => "broken",
+        }
+    }
+}
+
+/// A single cluster found by `segment`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cluster<'a> {
+    /// The text of the cluster, borrowed from the segmented text
+    pub text: &'a str,
+    /// Byte range of the cluster in the segmented text
+    pub range: Range<usize>,
+    pub kind: Syllable,
+    /// The character at `range` could not be attached to any syllable.
+    ///
+    /// This is a single character and `kind` is always `Syllable::Broken`.
+    pub broken: bool,
+}
+
+/// Looks up a script by the short codes used for the `data/` files.
+pub fn get_script(s: &str) -> Option<Script> {
+    match s {
+        "hi" => Some(Script::Devanagari),
+        "bn" => Some(Script::Bengali),
+        "ta" => Some(Script::Tamil),
+        "te" => Some(Script::Telugu),
+        "gu" => Some(Script::Gujarati),
+        "pa" => Some(Script::Gurmukhi),
+        "or" => Some(Script::Oriya),
+        "ml" => Some(Script::Malayalam),
+        "my" => Some(Script::Myanmar),
+        "kn" => Some(Script::Kannada),
+        "si" => Some(Script::Sinhala),
+        "km" => Some(Script::Khmer),
+        // Universal Shaping Engine scripts use their ISO 15924 codes
+        "bali" => Some(Script::Balinese),
+        "java" => Some(Script::Javanese),
+        "lana" => Some(Script::TaiTham),
+        "cakm" => Some(Script::Chakma),
+        "shrd" => Some(Script::Sharada),
+        "tirh" => Some(Script::Tirhuta),
+        "kawi" => Some(Script::Kawi),
+        _ => None,
+    }
+}
+
+/// A character that could not be attached to any syllable, as reported by `syllables`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BadCluster {
+    /// The character with the cluster before it, if any
+    pub text: String,
+    /// Byte offset of the character in `text`
+    pub start: usize,
+}
+
+impl BadCluster {
+    /// The character that failed, which is the same whichever format the report was in.
+    pub fn character(&self) -> char {
+        self.text[self.start..].chars().next().unwrap()
+    }
+}
+
+/// Parses a line written by `syllables`, such as `bad: "मा\u{310}" U+0310 count 3 first
+/// at line 12 column 3 byte 6: ...`, where the character is the last in the text.
+///
+/// Older reports such as `bad: "\u{34a}।" [U+34A] + [Danda] in line: ...` have the
+/// character followed by the rest of its line instead, and give a `start` of 0.
+pub fn parse_bad_line(line: &str) -> Option<BadCluster> {
+    let mut chars = line.strip_prefix("bad: \"")?.chars();
+    let mut text = String::new();
+    loop {
+        match chars.next()? {
+            '"' => break,
+            '\\' => match chars.next()? {
+                'n' => text.push('\n'),
+                't' => text.push('\t'),
+                'r' => text.push('\r'),
+                '0' => text.push('\0'),
+                'u' => {
+                    let hex: String = chars.by_ref().skip(1).take_while(|&c| c != '}').collect();
+                    text.push(std::char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                }
+                c => text.push(c),
+            },
+            c => text.push(c),
+        }
+    }
+    let start = if chars.as_str().starts_with(" [") {
+        0
+    } else {
+        text.char_indices().last()?.0
+    };
+    Some(BadCluster { text, start })
+}
+
+/// Describes the structure of a syllable as the shaping class of each of its characters,
+/// with the placement of marks, such as `Consonant Virama(Bottom) Consonant
+/// VowelDependent(Left)`.
+///
+/// Syllables with the same pattern only differ in which consonants or vowels they use.
+/// Characters without a shaping class are written as code points.
+pub fn pattern(s: &str, script: Script) -> String {
+    let class_name = match script {
+        Script::Myanmar => myanmar::class_name,
+        Script::Khmer => khmer::class_name,
+        Script::Balinese
+        | Script::Javanese
+        | Script::TaiTham
+        | Script::Chakma
+        | Script::Sharada
+        | Script::Tirhuta
+        | Script::Kawi => universal::class_name,
+        _ => indic::class_name,
+    };
+    s.chars().map(class_name).collect::<Vec<_>>().join(" ")
+}
+
+fn format_class<C: Debug, P: Debug>(ch: char, class: Option<C>, placement: Option<P>) -> String {
+    match (class, placement) {
+        (Some(class), Some(placement)) => {
+            let placement = format!("{:?}", placement);
+            format!("{:?}({})", class, placement.trim_end_matches("Position"))
+        }
+        (Some(class), None) => format!("{:?}", class),
+        (None, _) => format!("U+{:04X}", ch as u32),
+    }
+}
+
+/// The character shapers insert into broken syllables, in place of the missing base
+pub const DOTTED_CIRCLE: char = '\u{25CC}';
+
+/// Where a shaper inserts a dotted circle into `cluster` if it is a broken syllable, as a
+/// byte offset in the segmented text.
+///
+/// Like HarfBuzz, this is the start of the syllable, after any reph that starts it.
+/// Characters that could not be attached to any syllable get no dotted circle.
+pub fn dotted_circle(cluster: &Cluster, script: Script) -> Option<usize> {
+    if cluster.kind != Syllable::Broken || cluster.broken {
+        return None;
+    }
+    let repha = match script {
+        Script::Myanmar | Script::Khmer => |_| false,
+        Script::Balinese
+        | Script::Javanese
+        | Script::TaiTham
+        | Script::Chakma
+        | Script::Sharada
+        | Script::Tirhuta
+        | Script::Kawi => universal::repha,
+        _ => indic::repha,
+    };
+    let skipped = cluster.text.chars().take_while(|&ch| repha(ch));
+    Some(cluster.range.start + skipped.map(char::len_utf8).sum::<usize>())
+}
+
+/// Unicode normalization to apply to text before it is split into words or syllables.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Normalization {
+    AsIs,
+    Nfc,
+    Nfd,
+}
+
+impl Normalization {
+    pub fn normalize(self, text: &str) -> Cow<'_, str> {
+        match self {
+            Normalization::AsIs => Cow::from(text),
+            Normalization::Nfc if is_nfc(text) => Cow::from(text),
+            Normalization::Nfc => Cow::from(text.nfc().collect::<String>()),
+            Normalization::Nfd if is_nfd(text) => Cow::from(text),
+            Normalization::Nfd => Cow::from(text.nfd().collect::<String>()),
+        }
+    }
+}
+
+pub fn get_normalization(s: &str) -> Option<Normalization> {
+    match s {
+        "as-is" => Some(Normalization::AsIs),
+        "nfc" => Some(Normalization::Nfc),
+        "nfd" => Some(Normalization::Nfd),
+        _ => None,
+    }
+}
+
+/// How clusters are matched by `segment_with`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// The grammars written with parser combinators
+    Combinators,
+    /// The same grammar compiled into a deterministic automaton, which is faster.
+    ///
+    /// Only the Indic grammar is compiled so far; other scripts use the combinators.
+    Dfa,
+}
+
+pub fn get_backend(s: &str) -> Option<Backend> {
+    match s {
+        "combinators" => Some(Backend::Combinators),
+        "dfa" => Some(Backend::Dfa),
+        _ => None,
+    }
+}
+
+/// Splits `text` into clusters according to the grammar for `script`.
+///
+/// Numbers and modifying letters are not part of any cluster and are skipped.
+pub fn segment<'a>(text: &'a str, script: Script) -> impl Iterator<Item = Cluster<'a>> {
+    SyllableIter::new(text, script)
+}
+
+/// Splits `text` into clusters like `segment`, matching them with `backend`.
+pub fn segment_with<'a>(
+    text: &'a str,
+    script: Script,
+    backend: Backend,
+) -> impl Iterator<Item = Cluster<'a>> {
+    SyllableIter::with_backend(text, script, backend)
+}
+
+pub struct SyllableIter<'a> {
+    text: &'a str,
+    offset: usize,
+    match_fn: fn(&str) -> Option<(usize, Syllable)>,
+}
+
+impl<'a> SyllableIter<'a> {
+    pub fn new(text: &'a str, script: Script) -> Self {
+        SyllableIter::with_backend(text, script, Backend::Combinators)
+    }
+
+    pub fn with_backend(text: &'a str, script: Script, backend: Backend) -> Self {
+        let match_fn = match script {
+            Script::Myanmar => myanmar::match_syllable,
+            Script::Khmer => khmer::match_syllable,
+            Script::Balinese
+            | Script::Javanese
+            | Script::TaiTham
+            | Script::Chakma
+            | Script::Sharada
+            | Script::Tirhuta
+            | Script::Kawi => universal::match_syllable,
+            _ if backend == Backend::Dfa => indic::match_syllable_dfa,
+            _ => indic::match_syllable,
+        };
+        SyllableIter {
+            text,
+            offset: 0,
+            match_fn,
+        }
+    }
+
+    fn cluster(&self, start: usize, kind: Syllable, broken: bool) -> Cluster<'a> {
+        Cluster {
+            text: &self.text[start..self.offset],
+            range: start..self.offset,
+            kind,
+            broken,
+        }
+    }
+}
+
+impl<'a> Iterator for SyllableIter<'a> {
+    type Item = Cluster<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.offset;
+            let cs = &self.text[start..];
+            let first = cs.chars().next()?;
+            match (self.match_fn)(cs) {
+                Some((len, kind)) => {
+                    assert_ne!(len, 0);
+                    self.offset += len;
+                    return Some(self.cluster(start, kind, false));
+                }
+                None => {
+                    self.offset += first.len_utf8();
+                    // ignore numbers and modifying letters
+                    if !indic::other(first) {
+                        return Some(self.cluster(start, Syllable::Broken, true));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use super::diagnose::Role;
+use super::reorder::Position;
 use super::Syllable;
 
 // "A practical maximum cluster length is 31 characters."
@@ -30,6 +32,7 @@ enum ShapingClass {
     DottedCircle,
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Copy, Clone, Debug)]
 enum MarkPlacementSubclass {
     TopPosition,
@@ -49,18 +52,15 @@ fn shaping_class(ch: char) -> Option<ShapingClass> {
 // The definition of _consonant_ in the shaping docs excludes _ra_ but the only place it's
 // used, 'C', adds _ra_ back in, so we skip that.
 fn consonant(ch: char) -> bool {
-    match shaping_class(ch) {
-        Some(ShapingClass::Consonant | ShapingClass::ConsonantPlaceholder) => true,
-        _ => false,
-    }
+    matches!(
+        shaping_class(ch),
+        Some(ShapingClass::Consonant | ShapingClass::ConsonantPlaceholder)
+    )
 }
 
 // _vowel_
 fn vowel(ch: char) -> bool {
-    match shaping_class(ch) {
-        Some(ShapingClass::VowelIndependent) => true,
-        _ => false,
-    }
+    matches!(shaping_class(ch), Some(ShapingClass::VowelIndependent))
 }
 
 // _d_
@@ -109,17 +109,11 @@ fn halant(ch: char) -> bool {
 }
 
 fn zwj(ch: char) -> bool {
-    match shaping_class(ch) {
-        Some(ShapingClass::Joiner) => true,
-        _ => false,
-    }
+    matches!(shaping_class(ch), Some(ShapingClass::Joiner))
 }
 
 fn zwnj(ch: char) -> bool {
-    match shaping_class(ch) {
-        Some(ShapingClass::NonJoiner) => true,
-        _ => false,
-    }
+    matches!(shaping_class(ch), Some(ShapingClass::NonJoiner))
 }
 
 fn joiner(ch: char) -> bool {
@@ -140,10 +134,7 @@ fn asat(ch: char) -> bool {
 }
 
 fn consonant_with_stacker(ch: char) -> bool {
-    match shaping_class(ch) {
-        Some(ShapingClass::ConsonantWithStacker) => true,
-        _ => false,
-    }
+    matches!(shaping_class(ch), Some(ShapingClass::ConsonantWithStacker))
 }
 
 fn matra_pre(ch: char) -> bool {
@@ -246,16 +237,16 @@ fn punc(ch: char) -> bool {
 }
 
 // _ra_ _asat_ _halant_
-fn match_kinzi<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_kinzi(cs: &[char]) -> Option<usize> {
     match_seq(match_one(ra), match_seq(match_one(asat), match_one(halant)))(cs)
 }
 
-fn match_z<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_z(cs: &[char]) -> Option<usize> {
     match_one(joiner)(cs)
 }
 
 // _matrapre_* _matraabove_* _matrabelow_* _a_* (_db_ _asat_?)?
-fn match_vmain<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_vmain(cs: &[char]) -> Option<usize> {
     match_repeat_upto(
         MAX_REPEAT,
         match_one(matra_pre),
@@ -279,7 +270,7 @@ fn match_vmain<T: SyllableChar>(cs: &[T]) -> Option<usize> {
 }
 
 // _matrapost_ _mh_? _asat_* _matraabove_* _a_* (_db_ _asat_?)?
-fn match_vpost<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_vpost(cs: &[char]) -> Option<usize> {
     match_seq(
         match_one(matra_post),
         match_repeat_upto(
@@ -306,7 +297,7 @@ fn match_vpost<T: SyllableChar>(cs: &[T]) -> Option<usize> {
 }
 
 // _pt_ _a_* _db_? _asat_?
-fn match_pwo<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_pwo(cs: &[char]) -> Option<usize> {
     match_seq(
         match_one(pt),
         match_repeat_upto(
@@ -339,7 +330,7 @@ fn sm(ch: char) -> bool {
 }
 
 // Tcomplex= _asat_* Med Vmain Vpost* Pwo* _sm_* Z?
-fn match_t_complex<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_t_complex(cs: &[char]) -> Option<usize> {
     match_repeat_upto(
         MAX_REPEAT,
         match_one(asat),
@@ -362,12 +353,12 @@ fn match_t_complex<T: SyllableChar>(cs: &[T]) -> Option<usize> {
 }
 
 // _halant_ | Tcomplex
-fn match_syllable_tail<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_syllable_tail(cs: &[char]) -> Option<usize> {
     match_either(match_one(halant), match_t_complex)(cs)
 }
 
 // (_halant_ (C | _vowel_) _vs_?)
-fn match_halant_group<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_halant_group(cs: &[char]) -> Option<usize> {
     match_seq(
         match_seq(
             match_one(halant),
@@ -378,7 +369,7 @@ fn match_halant_group<T: SyllableChar>(cs: &[T]) -> Option<usize> {
 }
 
 // Med = _my_? _asat_? _mr_? ( (mw mh? ml? | mh ml? | ml) asat?)?
-fn match_medial_group<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_medial_group(cs: &[char]) -> Option<usize> {
     match_optional_seq(
         match_one(medial_ya),
         match_optional_seq(
@@ -389,7 +380,7 @@ fn match_medial_group<T: SyllableChar>(cs: &[T]) -> Option<usize> {
 }
 
 // (mw mh? ml? | mh ml? | ml) asat?
-fn match_medial_group2<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_medial_group2(cs: &[char]) -> Option<usize> {
     match_seq(
         match_either(
             match_medial_group2a,
@@ -400,7 +391,7 @@ fn match_medial_group2<T: SyllableChar>(cs: &[T]) -> Option<usize> {
 }
 
 // mw mh? ml?
-fn match_medial_group2a<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_medial_group2a(cs: &[char]) -> Option<usize> {
     match_seq(
         match_one(medial_wa),
         match_optional_seq(match_one(medial_ha), match_optional(match_one(medial_la))),
@@ -408,12 +399,12 @@ fn match_medial_group2a<T: SyllableChar>(cs: &[T]) -> Option<usize> {
 }
 
 // mh ml?
-fn match_medial_group2b<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_medial_group2b(cs: &[char]) -> Option<usize> {
     match_seq(match_one(medial_ha), match_optional(match_one(medial_la)))(cs)
 }
 
 // G = _gb_ | _d_ | _punc_
-fn match_g<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_g(cs: &[char]) -> Option<usize> {
     match_either(
         match_one(generic_base),
         match_either(match_one(digit), match_one(punc)),
@@ -421,7 +412,7 @@ fn match_g<T: SyllableChar>(cs: &[T]) -> Option<usize> {
 }
 
 // (C | _vowel_ | G)
-fn match_initial_group<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_initial_group(cs: &[char]) -> Option<usize> {
     match_either(
         match_one(consonant),
         match_either(match_one(vowel), match_g),
@@ -429,7 +420,7 @@ fn match_initial_group<T: SyllableChar>(cs: &[T]) -> Option<usize> {
 }
 
 // (K | _cs_)? (C | _vowel_ | G) _vs_? (_halant_ (C | _vowel_) _vs_?)* Tail
-fn match_consonant_syllable<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_consonant_syllable(cs: &[char]) -> Option<usize> {
     match_optional_seq(
         match_either(match_kinzi, match_one(consonant_with_stacker)),
         match_seq(
@@ -442,15 +433,101 @@ fn match_consonant_syllable<T: SyllableChar>(cs: &[T]) -> Option<usize> {
     )(cs)
 }
 
-fn match_standalone<T: SyllableChar>(cs: &[T]) -> Option<usize> {
+fn match_standalone(cs: &[char]) -> Option<usize> {
     match_one(standalone)(cs)
 }
 
-pub fn match_syllable<T: SyllableChar>(cs: &[T]) -> Option<(usize, Syllable)> {
-    match match_consonant_syllable(cs) {
+pub fn match_syllable(cs: &str) -> Option<(usize, Syllable)> {
+    match_chars(cs, |cs| match match_consonant_syllable(cs) {
         Some(len) => Some((len, Syllable::Consonant)),
         None => match_standalone(cs).map(|len| (len, Syllable::Broken)),
+    })
+}
+
+/// The shaping class of `ch` for `super::pattern`, with its placement if it is a mark.
+pub(crate) fn class_name(ch: char) -> String {
+    let (class, placement) = myanmar_character(ch);
+    super::format_class(ch, class, placement)
+}
+
+/// What `ch` does in a syllable, for `super::diagnose`.
+pub(crate) fn role(ch: char) -> Option<Role> {
+    use self::ShapingClass::*;
+
+    Some(match myanmar_character(ch).0? {
+        Consonant | ConsonantPlaceholder | ConsonantWithStacker | Placeholder | DottedCircle => {
+            Role::Base
+        }
+        VowelIndependent => Role::VowelIndependent,
+        InvisibleStacker | PureKiller => Role::Virama,
+        VowelDependent => Role::VowelDependent,
+        Bindu | Visarga | ConsonantMedial | ToneMarker => Role::Mark,
+        Joiner | NonJoiner => Role::Joiner,
+        Number | Symbol => Role::Other,
+    })
+}
+
+// The bases of HarfBuzz's reordering: C, CS, Ra, IV, GB and the dotted circle
+fn base(ch: char) -> bool {
+    consonant(ch) || consonant_with_stacker(ch) || vowel(ch) || generic_base(ch)
+}
+
+/// Puts the characters of a syllable in the order a shaper displays them, for
+/// `super::reorder`, as HarfBuzz does.
+///
+/// The kinzi goes after the base, and medial ra and the pre-base matras before it. A dot
+/// below after an asat is first moved before it, which is the canonical order.
+pub(crate) fn reorder(syllable: &str) -> Vec<(char, Position)> {
+    let mut chars: Vec<char> = syllable.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let run = chars[i..]
+            .iter()
+            .take_while(|&&ch| asat(ch) || dot_below(ch))
+            .count();
+        chars[i..i + run].sort_by_key(|&ch| !dot_below(ch));
+        i += run.max(1);
+    }
+    let kinzi = match match_kinzi(&chars) {
+        Some(_) if chars.len() > 3 => 3,
+        _ => 0,
+    };
+    let base_index = (kinzi..chars.len())
+        .find(|&i| base(chars[i]))
+        .unwrap_or(kinzi);
+
+    let mut reordered: Vec<(char, Position)> = Vec::new();
+    // the position of marks after the base, which only moves forward: below-base matras
+    // (with any anusvara between them put before them) and then everything after them
+    let mut after_base = Position::AfterMain;
+    for (i, &ch) in chars.iter().enumerate() {
+        let position = if i < kinzi {
+            Position::AfterMain
+        } else if i < base_index {
+            Position::PreBaseConsonant
+        } else if i == base_index {
+            Position::Base
+        } else if medial_ra(ch) {
+            Position::PreBaseReordering
+        } else if matra_pre(ch) {
+            Position::PreBaseMatra
+        } else if variation_selector(ch) {
+            reordered[i - 1].1
+        } else if after_base == Position::BelowBase && a(ch) {
+            Position::BeforeSub
+        } else {
+            if after_base == Position::AfterMain && matra_below(ch) {
+                after_base = Position::BelowBase;
+            } else if after_base == Position::BelowBase && !matra_below(ch) {
+                after_base = Position::AfterSub;
+            }
+            after_base
+        };
+        reordered.push((ch, position));
     }
+    // a stable sort, so characters in the same position stay in logical order
+    reordered.sort_by_key(|&(_, position)| position);
+    reordered
 }
 
 /////////////////////////////////////////////////////////////////////////////
@@ -477,7 +554,7 @@ fn myanmar_character(ch: char) -> (Option<ShapingClass>, Option<MarkPlacementSub
         0x100B => (Some(Consonant), None),        // ဋ Tta
         0x100C => (Some(Consonant), None),        // ဌ Ttha
         0x100D => (Some(Consonant), None),        // ဍ Dda
-        0x100E => (Some(Consonant), None),        // ဎ DDha
+        0x100E => (Some(Consonant), None),        // ဎ Ddha
         0x100F => (Some(Consonant), None),        // ဏ Nna
         0x1010 => (Some(Consonant), None),        // တ Ta
         0x1011 => (Some(Consonant), None),        // ထ Tha
@@ -522,107 +599,107 @@ fn myanmar_character(ch: char) -> (Option<ShapingClass>, Option<MarkPlacementSub
         0x1038 => (Some(Visarga), Some(RightPosition)), // း Visarga
         0x1039 => (Some(InvisibleStacker), None), // ္ Virama
         0x103A => (Some(PureKiller), Some(TopPosition)), // ် Asat
-        0x103B => (Some(ConsonantMedial), Some(RightPosition)), // ျ Sign Medial Ya
-        0x103C => (Some(ConsonantMedial), Some(TopLeftAndBottomPosition)), // ြ Sign Medial Ra
-        0x103D => (Some(ConsonantMedial), Some(BottomPosition)), // ွ Sign Medial Wa
-        0x103E => (Some(ConsonantMedial), Some(BottomPosition)), // ှ Sign Medial Ha
-        0x103F => (Some(Consonant), None),        // ဿ Great Sa
-        0x1040 => (Some(Number), None),           // ၀ Digit Zero
-        0x1041 => (Some(Number), None),           // ၁ Digit One
-        0x1042 => (Some(Number), None),           // ၂ Digit Two
-        0x1043 => (Some(Number), None),           // ၃ Digit Three
-        0x1044 => (Some(Number), None),           // ၄ Digit Four
-        0x1045 => (Some(Number), None),           // ၅ Digit Five
-        0x1046 => (Some(Number), None),           // ၆ Digit Six
-        0x1047 => (Some(Number), None),           // ၇ Digit Seven
-        0x1048 => (Some(Number), None),           // ၈ Digit Eight
-        0x1049 => (Some(Number), None),           // ၉ Digit Nine
-        0x104A => (None, None),                   // ၊ Little Section
-        0x104B => (None, None),                   // ။ Section
-        0x104C => (None, None),                   // ၌ Locative
-        0x104D => (None, None),                   // ၍ Completed
-        0x104E => (Some(ConsonantPlaceholder), None), // ၎ Aforementioned
-        0x104F => (None, None),                   // ၏ Genitive
-        0x1050 => (Some(Consonant), None),        // ၐ Sha
-        0x1051 => (Some(Consonant), None),        // ၑ Ssa
-        0x1052 => (Some(VowelIndependent), None), // ၒ Vocalic R
-        0x1053 => (Some(VowelIndependent), None), // ၓ Vocalic Rr
-        0x1054 => (Some(VowelIndependent), None), // ၔ Vocalic L
-        0x1055 => (Some(VowelIndependent), None), // ၕ Vocalic Ll
-        0x1056 => (Some(VowelDependent), Some(RightPosition)), // ၖ Sign Vocalic R
-        0x1057 => (Some(VowelDependent), Some(RightPosition)), // ၗ Sign Vocalic Rr
-        0x1058 => (Some(VowelDependent), Some(BottomPosition)), // ၘ Sign Vocalic L
-        0x1059 => (Some(VowelDependent), Some(BottomPosition)), // ၙ Sign Vocalic Ll
-        0x105A => (Some(Consonant), None),        // ၚ Mon Nga
-        0x105B => (Some(Consonant), None),        // ၛ Mon Jha
-        0x105C => (Some(Consonant), None),        // ၜ Mon Bba
-        0x105D => (Some(Consonant), None),        // ၝ Mon Bbe
-        0x105E => (Some(ConsonantMedial), Some(BottomPosition)), // ၞ Sign Mon Medial Na
-        0x105F => (Some(ConsonantMedial), Some(BottomPosition)), // ၟ Sign Mon Medial Ma
-        0x1060 => (Some(ConsonantMedial), Some(BottomPosition)), // ၠ Sign Mon Medial La
-        0x1061 => (Some(Consonant), None),        // ၡ Sgaw Karen Sha
-        0x1062 => (Some(VowelDependent), Some(RightPosition)), // ၢ Sign Sgaw Karen Eu
-        0x1063 => (Some(ToneMarker), Some(RightPosition)), // ၣ Tone Sgaw Karen Hathi
-        0x1064 => (Some(ToneMarker), Some(RightPosition)), // ၤ Tone Sgaw Karen Ke Pho
-        0x1065 => (Some(Consonant), None),        // ၥ Western Pwo Karen Tha
-        0x1066 => (Some(Consonant), None),        // ၦ Western Pwo Karen Pwa
-        0x1067 => (Some(VowelDependent), Some(RightPosition)), // ၧ Sign Western Pwo Karen Eu
-        0x1068 => (Some(VowelDependent), Some(RightPosition)), // ၨ Sign Western Pwo Karen Ue
-        0x1069 => (Some(ToneMarker), Some(RightPosition)), // ၩ Sign Western Pwo Karen Tone 1
-        0x106A => (Some(ToneMarker), Some(RightPosition)), // ၪ Sign Western Pwo Karen Tone 2
-        0x106B => (Some(ToneMarker), Some(RightPosition)), // ၫ Sign Western Pwo Karen Tone 3
-        0x106C => (Some(ToneMarker), Some(RightPosition)), // ၬ Sign Western Pwo Karen Tone 4
-        0x106D => (Some(ToneMarker), Some(RightPosition)), // ၭ Sign Western Pwo Karen Tone 5
-        0x106E => (Some(Consonant), None),        // ၮ Eastern Pwo Karen Nna
-        0x106F => (Some(Consonant), None),        // ၯ Eastern Pwo Karen Ywa
-        0x1070 => (Some(Consonant), None),        // ၰ Eastern Pwo Karen Ghwa
-        0x1071 => (Some(VowelDependent), Some(TopPosition)), // ၱ Sign Geba Karen I
-        0x1072 => (Some(VowelDependent), Some(TopPosition)), // ၲ Sign Kayah Oe
-        0x1073 => (Some(VowelDependent), Some(TopPosition)), // ၳ Sign Kayah U
-        0x1074 => (Some(VowelDependent), Some(TopPosition)), // ၴ Sign Kayah Ee
-        0x1075 => (Some(Consonant), None),        // ၵ Shan Ka
-        0x1076 => (Some(Consonant), None),        // ၶ Shan Kha
-        0x1077 => (Some(Consonant), None),        // ၷ Shan Ga
-        0x1078 => (Some(Consonant), None),        // ၸ Shan Ca
-        0x1079 => (Some(Consonant), None),        // ၹ Shan Za
-        0x107A => (Some(Consonant), None),        // ၺ Shan Nya
-        0x107B => (Some(Consonant), None),        // ၻ Shan Da
-        0x107C => (Some(Consonant), None),        // ၼ Shan Na
-        0x107D => (Some(Consonant), None),        // ၽ Shan Pha
-        0x107E => (Some(Consonant), None),        // ၾ Shan Fa
-        0x107F => (Some(Consonant), None),        // ၿ Shan Ba
-        0x1080 => (Some(Consonant), None),        // ႀ Shan Tha
-        0x1081 => (Some(Consonant), None),        // ႁ Shan Ha
-        0x1082 => (Some(ConsonantMedial), Some(BottomPosition)), // ႂ Sign Shan Medial Wa
-        0x1083 => (Some(VowelDependent), Some(RightPosition)), // ႃ Sign Shan Aa
-        0x1084 => (Some(VowelDependent), Some(LeftPosition)), // ႄ Sign Shan E
-        0x1085 => (Some(VowelDependent), Some(TopPosition)), // ႅ Sign Shan E Above
-        0x1086 => (Some(VowelDependent), Some(TopPosition)), // ႆ Sign Shan Final Y
-        0x1087 => (Some(ToneMarker), Some(RightPosition)), // ႇ Sign Shan Tone 2
-        0x1088 => (Some(ToneMarker), Some(RightPosition)), // ႈ Sign Shan Tone 3
-        0x1089 => (Some(ToneMarker), Some(RightPosition)), // ႉ Sign Shan Tone 5
-        0x108A => (Some(ToneMarker), Some(RightPosition)), // ႊ Sign Shan Tone 6
-        0x108B => (Some(ToneMarker), Some(RightPosition)), // ႋ Sign Shan Council Tone 2
-        0x108C => (Some(ToneMarker), Some(RightPosition)), // ႌ Sign Shan Council Tone 3
-        0x108D => (Some(ToneMarker), Some(BottomPosition)), // ႍ Sign Shan Council Emphatic Tone
-        0x108E => (Some(Consonant), None),        // ႎ Rumai Palaung Fa
-        0x108F => (Some(ToneMarker), Some(RightPosition)), // ႏ Sign Rumai Palaung Tone 5
-        0x1090 => (Some(Number), None),           // ႐ Shan Digit Zero
-        0x1091 => (Some(Number), None),           // ႑ Shan Digit One
-        0x1092 => (Some(Number), None),           // ႒ Shan Digit Two
-        0x1093 => (Some(Number), None),           // ႓ Shan Digit Three
-        0x1094 => (Some(Number), None),           // ႔ Shan Digit Four
-        0x1095 => (Some(Number), None),           // ႕ Shan Digit Five
-        0x1096 => (Some(Number), None),           // ႖ Shan Digit Six
-        0x1097 => (Some(Number), None),           // ႗ Shan Digit Seven
-        0x1098 => (Some(Number), None),           // ႘ Shan Digit Eight
-        0x1099 => (Some(Number), None),           // ႙ Shan Digit Nine
-        0x109A => (Some(ToneMarker), Some(RightPosition)), // ႚ Sign Khamti Tone 1
-        0x109B => (Some(ToneMarker), Some(RightPosition)), // ႛ Sign Khamti Tone 3
-        0x109C => (Some(VowelDependent), Some(RightPosition)), // ႜ Sign Aiton A
-        0x109D => (Some(VowelDependent), Some(TopPosition)), // ႝ Sign Aiton Ai
-        0x109E => (Some(Symbol), None),           // ႞ Shan One
-        0x109F => (Some(Symbol), None),           // ႟ Shan Exclamation
+        0x103B => (Some(ConsonantMedial), Some(RightPosition)), // ျ Consonant Sign Medial Ya
+        0x103C => (Some(ConsonantMedial), Some(TopLeftAndBottomPosition)), // ြ Consonant Sign Medial Ra
+        0x103D => (Some(ConsonantMedial), Some(BottomPosition)), // ွ Consonant Sign Medial Wa
+        0x103E => (Some(ConsonantMedial), Some(BottomPosition)), // ှ Consonant Sign Medial Ha
+        0x103F => (Some(Consonant), None),                       // ဿ Great Sa
+        0x1040 => (Some(Number), None),                          // ၀ Digit Zero
+        0x1041 => (Some(Number), None),                          // ၁ Digit One
+        0x1042 => (Some(Number), None),                          // ၂ Digit Two
+        0x1043 => (Some(Number), None),                          // ၃ Digit Three
+        0x1044 => (Some(Number), None),                          // ၄ Digit Four
+        0x1045 => (Some(Number), None),                          // ၅ Digit Five
+        0x1046 => (Some(Number), None),                          // ၆ Digit Six
+        0x1047 => (Some(Number), None),                          // ၇ Digit Seven
+        0x1048 => (Some(Number), None),                          // ၈ Digit Eight
+        0x1049 => (Some(Number), None),                          // ၉ Digit Nine
+        0x104A => (None, None),                                  // ၊ Little Section
+        0x104B => (None, None),                                  // ။ Section
+        0x104C => (None, None),                                  // ၌ Symbol Locative
+        0x104D => (None, None),                                  // ၍ Symbol Completed
+        0x104E => (Some(ConsonantPlaceholder), None),            // ၎ Symbol Aforementioned
+        0x104F => (None, None),                                  // ၏ Symbol Genitive
+        0x1050 => (Some(Consonant), None),                       // ၐ Sha
+        0x1051 => (Some(Consonant), None),                       // ၑ Ssa
+        0x1052 => (Some(VowelIndependent), None),                // ၒ Vocalic R
+        0x1053 => (Some(VowelIndependent), None),                // ၓ Vocalic Rr
+        0x1054 => (Some(VowelIndependent), None),                // ၔ Vocalic L
+        0x1055 => (Some(VowelIndependent), None),                // ၕ Vocalic Ll
+        0x1056 => (Some(VowelDependent), Some(RightPosition)),   // ၖ Sign Vocalic R
+        0x1057 => (Some(VowelDependent), Some(RightPosition)),   // ၗ Sign Vocalic Rr
+        0x1058 => (Some(VowelDependent), Some(BottomPosition)),  // ၘ Sign Vocalic L
+        0x1059 => (Some(VowelDependent), Some(BottomPosition)),  // ၙ Sign Vocalic Ll
+        0x105A => (Some(Consonant), None),                       // ၚ Mon Nga
+        0x105B => (Some(Consonant), None),                       // ၛ Mon Jha
+        0x105C => (Some(Consonant), None),                       // ၜ Mon Bba
+        0x105D => (Some(Consonant), None),                       // ၝ Mon Bbe
+        0x105E => (Some(ConsonantMedial), Some(BottomPosition)), // ၞ Consonant Sign Mon Medial Na
+        0x105F => (Some(ConsonantMedial), Some(BottomPosition)), // ၟ Consonant Sign Mon Medial Ma
+        0x1060 => (Some(ConsonantMedial), Some(BottomPosition)), // ၠ Consonant Sign Mon Medial La
+        0x1061 => (Some(Consonant), None),                       // ၡ Sgaw Karen Sha
+        0x1062 => (Some(VowelDependent), Some(RightPosition)),   // ၢ Sign Sgaw Karen Eu
+        0x1063 => (Some(ToneMarker), Some(RightPosition)),       // ၣ Tone Mark Sgaw Karen Hathi
+        0x1064 => (Some(ToneMarker), Some(RightPosition)),       // ၤ Tone Mark Sgaw Karen Ke Pho
+        0x1065 => (Some(Consonant), None),                       // ၥ Western Pwo Karen Tha
+        0x1066 => (Some(Consonant), None),                       // ၦ Western Pwo Karen Pwa
+        0x1067 => (Some(VowelDependent), Some(RightPosition)),   // ၧ Sign Western Pwo Karen Eu
+        0x1068 => (Some(VowelDependent), Some(RightPosition)),   // ၨ Sign Western Pwo Karen Ue
+        0x1069 => (Some(ToneMarker), Some(RightPosition)),       // ၩ Western Pwo Karen Tone-1
+        0x106A => (Some(ToneMarker), Some(RightPosition)),       // ၪ Western Pwo Karen Tone-2
+        0x106B => (Some(ToneMarker), Some(RightPosition)),       // ၫ Western Pwo Karen Tone-3
+        0x106C => (Some(ToneMarker), Some(RightPosition)),       // ၬ Western Pwo Karen Tone-4
+        0x106D => (Some(ToneMarker), Some(RightPosition)),       // ၭ Western Pwo Karen Tone-5
+        0x106E => (Some(Consonant), None),                       // ၮ Eastern Pwo Karen Nna
+        0x106F => (Some(Consonant), None),                       // ၯ Eastern Pwo Karen Ywa
+        0x1070 => (Some(Consonant), None),                       // ၰ Eastern Pwo Karen Ghwa
+        0x1071 => (Some(VowelDependent), Some(TopPosition)),     // ၱ Sign Geba Karen I
+        0x1072 => (Some(VowelDependent), Some(TopPosition)),     // ၲ Sign Kayah Oe
+        0x1073 => (Some(VowelDependent), Some(TopPosition)),     // ၳ Sign Kayah U
+        0x1074 => (Some(VowelDependent), Some(TopPosition)),     // ၴ Sign Kayah Ee
+        0x1075 => (Some(Consonant), None),                       // ၵ Shan Ka
+        0x1076 => (Some(Consonant), None),                       // ၶ Shan Kha
+        0x1077 => (Some(Consonant), None),                       // ၷ Shan Ga
+        0x1078 => (Some(Consonant), None),                       // ၸ Shan Ca
+        0x1079 => (Some(Consonant), None),                       // ၹ Shan Za
+        0x107A => (Some(Consonant), None),                       // ၺ Shan Nya
+        0x107B => (Some(Consonant), None),                       // ၻ Shan Da
+        0x107C => (Some(Consonant), None),                       // ၼ Shan Na
+        0x107D => (Some(Consonant), None),                       // ၽ Shan Pha
+        0x107E => (Some(Consonant), None),                       // ၾ Shan Fa
+        0x107F => (Some(Consonant), None),                       // ၿ Shan Ba
+        0x1080 => (Some(Consonant), None),                       // ႀ Shan Tha
+        0x1081 => (Some(Consonant), None),                       // ႁ Shan Ha
+        0x1082 => (Some(ConsonantMedial), Some(BottomPosition)), // ႂ Consonant Sign Shan Medial Wa
+        0x1083 => (Some(VowelDependent), Some(RightPosition)),   // ႃ Sign Shan Aa
+        0x1084 => (Some(VowelDependent), Some(LeftPosition)),    // ႄ Sign Shan E
+        0x1085 => (Some(VowelDependent), Some(TopPosition)),     // ႅ Sign Shan E Above
+        0x1086 => (Some(VowelDependent), Some(TopPosition)),     // ႆ Sign Shan Final Y
+        0x1087 => (Some(ToneMarker), Some(RightPosition)),       // ႇ Shan Tone-2
+        0x1088 => (Some(ToneMarker), Some(RightPosition)),       // ႈ Shan Tone-3
+        0x1089 => (Some(ToneMarker), Some(RightPosition)),       // ႉ Shan Tone-5
+        0x108A => (Some(ToneMarker), Some(RightPosition)),       // ႊ Shan Tone-6
+        0x108B => (Some(ToneMarker), Some(RightPosition)),       // ႋ Shan Council Tone-2
+        0x108C => (Some(ToneMarker), Some(RightPosition)),       // ႌ Shan Council Tone-3
+        0x108D => (Some(ToneMarker), Some(BottomPosition)),      // ႍ Shan Council Emphatic Tone
+        0x108E => (Some(Consonant), None),                       // ႎ Rumai Palaung Fa
+        0x108F => (Some(ToneMarker), Some(RightPosition)),       // ႏ Rumai Palaung Tone-5
+        0x1090 => (Some(Number), None),                          // ႐ Shan Digit Zero
+        0x1091 => (Some(Number), None),                          // ႑ Shan Digit One
+        0x1092 => (Some(Number), None),                          // ႒ Shan Digit Two
+        0x1093 => (Some(Number), None),                          // ႓ Shan Digit Three
+        0x1094 => (Some(Number), None),                          // ႔ Shan Digit Four
+        0x1095 => (Some(Number), None),                          // ႕ Shan Digit Five
+        0x1096 => (Some(Number), None),                          // ႖ Shan Digit Six
+        0x1097 => (Some(Number), None),                          // ႗ Shan Digit Seven
+        0x1098 => (Some(Number), None),                          // ႘ Shan Digit Eight
+        0x1099 => (Some(Number), None),                          // ႙ Shan Digit Nine
+        0x109A => (Some(ToneMarker), Some(RightPosition)),       // ႚ Khamti Tone-1
+        0x109B => (Some(ToneMarker), Some(RightPosition)),       // ႛ Khamti Tone-3
+        0x109C => (Some(VowelDependent), Some(RightPosition)),   // ႜ Sign Aiton A
+        0x109D => (Some(VowelDependent), Some(TopPosition)),     // ႝ Sign Aiton Ai
+        0x109E => (Some(Symbol), None),                          // ႞ Symbol Shan One
+        0x109F => (Some(Symbol), None),                          // ႟ Symbol Shan Exclamation
 
         // Myanmar Extended A character table
         0xAA60 => (Some(Consonant), None), // ꩠ Khamti Ga
@@ -641,20 +718,20 @@ fn myanmar_character(ch: char) -> (Option<ShapingClass>, Option<MarkPlacementSub
         0xAA6D => (Some(Consonant), None), // ꩭ Khamti Ha
         0xAA6E => (Some(Consonant), None), // ꩮ Khamti Hha
         0xAA6F => (Some(Consonant), None), // ꩯ Khamti Fa
-        0xAA70 => (None, None),            // ꩰ Khamti Reduplication
+        0xAA70 => (None, None),            // ꩰ Modifier Letter Khamti Reduplication
         0xAA71 => (Some(Consonant), None), // ꩱ Khamti Xa
         0xAA72 => (Some(Consonant), None), // ꩲ Khamti Za
         0xAA73 => (Some(Consonant), None), // ꩳ Khamti Ra
-        0xAA74 => (Some(ConsonantPlaceholder), None), // ꩴ Khamti Oay
-        0xAA75 => (Some(ConsonantPlaceholder), None), // ꩵ Khamti Qn
-        0xAA76 => (Some(ConsonantPlaceholder), None), // ꩶ Khamti Hm
-        0xAA77 => (Some(Symbol), None),    // ꩷ Khamti Aiton Exclamation
-        0xAA78 => (Some(Symbol), None),    // ꩸ Khamti Aiton One
-        0xAA79 => (Some(Symbol), None),    // ꩹ Khamti Aiton Two
-        0xAA7A => (Some(Consonant), None), // ꩺ Khamti Aiton Ra
-        0xAA7B => (Some(ToneMarker), Some(RightPosition)), // ꩻ Sign Pao Karen Tone
-        0xAA7C => (Some(ToneMarker), Some(TopPosition)), // ꩼ Sign Tai Laing Tone 2
-        0xAA7D => (Some(ToneMarker), Some(RightPosition)), // ꩽ Sign Tai Laing Tone 5
+        0xAA74 => (Some(ConsonantPlaceholder), None), // ꩴ Logogram Khamti Oay
+        0xAA75 => (Some(ConsonantPlaceholder), None), // ꩵ Logogram Khamti Qn
+        0xAA76 => (Some(ConsonantPlaceholder), None), // ꩶ Logogram Khamti Hm
+        0xAA77 => (Some(Symbol), None),    // ꩷ Symbol Aiton Exclamation
+        0xAA78 => (Some(Symbol), None),    // ꩸ Symbol Aiton One
+        0xAA79 => (Some(Symbol), None),    // ꩹ Symbol Aiton Two
+        0xAA7A => (Some(Consonant), None), // ꩺ Aiton Ra
+        0xAA7B => (Some(ToneMarker), Some(RightPosition)), // ꩻ Pao Karen Tone
+        0xAA7C => (Some(ToneMarker), Some(TopPosition)), // ꩼ Tai Laing Tone-2
+        0xAA7D => (Some(ToneMarker), Some(RightPosition)), // ꩽ Tai Laing Tone-5
         0xAA7E => (Some(Consonant), None), // ꩾ Shwe Palaung Cha
         0xAA7F => (Some(Consonant), None), // ꩿ Shwe Palaung Sha
 
@@ -664,8 +741,8 @@ fn myanmar_character(ch: char) -> (Option<ShapingClass>, Option<MarkPlacementSub
         0xA9E2 => (Some(Consonant), None), // ꧢ Shan Jha
         0xA9E3 => (Some(Consonant), None), // ꧣ Shan Nna
         0xA9E4 => (Some(Consonant), None), // ꧤ Shan Bha
-        0xA9E5 => (Some(VowelDependent), Some(TopPosition)), // ꧥ Sign Shan Saw
-        0xA9E6 => (None, None),            // ꧦ Shan Reduplication
+        0xA9E5 => (Some(VowelDependent), Some(TopPosition)), // ꧥ Shan Saw
+        0xA9E6 => (None, None),            // ꧦ Modifier Letter Shan Reduplication
         0xA9E7 => (Some(Consonant), None), // ꧧ Tai Laing Nya
         0xA9E8 => (Some(Consonant), None), // ꧨ Tai Laing Fa
         0xA9E9 => (Some(Consonant), None), // ꧩ Tai Laing Ga
@@ -692,15 +769,15 @@ fn myanmar_character(ch: char) -> (Option<ShapingClass>, Option<MarkPlacementSub
         0xA9FE => (Some(Consonant), None), // ꧾ Tai Laing Bha
 
         // Miscellaneous character table
-        0x00A0 => (Some(Placeholder), None),  //   No-break space
-        0x200C => (Some(NonJoiner), None),    // ‌ Zero-width non-joiner
-        0x200D => (Some(Joiner), None),       // ‍ Zero-width joiner
+        0x00A0 => (Some(Placeholder), None),  //   No-Break Space
+        0x200C => (Some(NonJoiner), None),    // ‌ Zero Width Non-Joiner
+        0x200D => (Some(Joiner), None),       // ‍ Zero Width Joiner
         0x2010 => (Some(Placeholder), None),  // ‐ Hyphen
-        0x2011 => (Some(Placeholder), None),  // ‑ No-break hyphen
-        0x2012 => (Some(Placeholder), None),  // ‒ Figure dash
-        0x2013 => (Some(Placeholder), None),  // – En dash
-        0x2014 => (Some(Placeholder), None),  // — Em dash
-        0x25CC => (Some(DottedCircle), None), // ◌ Dotted circle
+        0x2011 => (Some(Placeholder), None),  // ‑ Non-Breaking Hyphen
+        0x2012 => (Some(Placeholder), None),  // ‒ Figure Dash
+        0x2013 => (Some(Placeholder), None),  // – En Dash
+        0x2014 => (Some(Placeholder), None),  // — Em Dash
+        0x25CC => (Some(DottedCircle), None), // ◌ Dotted Circle
 
         _ => (None, None),
     }
@@ -708,6 +785,10 @@ fn myanmar_character(ch: char) -> (Option<ShapingClass>, Option<MarkPlacementSub
 
 /////////////////////////
 
+/// A character as the grammar matchers see it.
+///
+/// The matchers work on slices of characters decoded once by `match_chars`, rather than on
+/// UTF-8 that they would decode again every time they looked at a character.
 pub trait SyllableChar {
     fn char(&self) -> char;
 }
@@ -718,23 +799,39 @@ impl SyllableChar for char {
     }
 }
 
+/// Decodes as many characters from the start of `cs` as a cluster can have and matches
+/// them with `f`, returning the length of the match in bytes.
+pub fn match_chars(
+    cs: &str,
+    f: impl Fn(&[char]) -> Option<(usize, Syllable)>,
+) -> Option<(usize, Syllable)> {
+    let mut chars = ['\0'; MAX_CLUSTER_LEN + 1];
+    let mut n = 0;
+    for (slot, ch) in chars.iter_mut().zip(cs.chars()) {
+        *slot = ch;
+        n += 1;
+    }
+    let (len, syllable) = f(&chars[..n])?;
+    Some((chars[..len].iter().map(|c| c.len_utf8()).sum(), syllable))
+}
+
 /// Matches against a single character
+///
+/// Like all of the matchers, returns the length of the match in characters.
 pub fn match_one<T: SyllableChar>(f: impl Fn(char) -> bool) -> impl Fn(&[T]) -> Option<usize> {
-    move |cs: &[T]| match cs.get(0) {
+    move |cs: &[T]| match cs.first() {
         Some(c) if f(c.char()) => Some(1),
         _ => None,
     }
 }
 
 /// Succeeds if `f` succeeds otherwise consumes nothing
-pub fn match_optional<T: SyllableChar>(
-    f: impl Fn(&[T]) -> Option<usize>,
-) -> impl Fn(&[T]) -> Option<usize> {
+pub fn match_optional<T>(f: impl Fn(&[T]) -> Option<usize>) -> impl Fn(&[T]) -> Option<usize> {
     move |cs: &[T]| f(cs).or(Some(0))
 }
 
 /// `f? g`: matches either `g` or `f g`
-pub fn match_optional_seq<T: SyllableChar>(
+pub fn match_optional_seq<T>(
     f: impl Fn(&[T]) -> Option<usize>,
     g: impl Fn(&[T]) -> Option<usize>,
 ) -> impl Fn(&[T]) -> Option<usize> {
@@ -742,7 +839,7 @@ pub fn match_optional_seq<T: SyllableChar>(
 }
 
 #[allow(dead_code)]
-pub fn match_repeat_num<T: SyllableChar>(
+pub fn match_repeat_num<T>(
     num: usize,
     f: impl Fn(&[T]) -> Option<usize>,
 ) -> impl Fn(&[T]) -> Option<usize> {
@@ -758,7 +855,7 @@ pub fn match_repeat_num<T: SyllableChar>(
 }
 
 /// Match up to `max` instances of `f`, followed by `g`
-pub fn match_repeat_upto<T: SyllableChar>(
+pub fn match_repeat_upto<T>(
     max: usize,
     f: impl Fn(&[T]) -> Option<usize>,
     g: impl Fn(&[T]) -> Option<usize>,
@@ -787,7 +884,7 @@ pub fn match_repeat_upto<T: SyllableChar>(
 /// Match `f1` followed by `f2`.
 ///
 /// Fails if `f1` or `f2` fail.
-pub fn match_seq<T: SyllableChar>(
+pub fn match_seq<T>(
     f1: impl Fn(&[T]) -> Option<usize>,
     f2: impl Fn(&[T]) -> Option<usize>,
 ) -> impl Fn(&[T]) -> Option<usize> {
@@ -801,7 +898,7 @@ pub fn match_seq<T: SyllableChar>(
 /// Matches whichever of `f1` or `f2` match the most input.
 ///
 /// Uses `f2`'s match if they match the same input
-pub fn match_either<T: SyllableChar>(
+pub fn match_either<T>(
     f1: impl Fn(&[T]) -> Option<usize>,
     f2: impl Fn(&[T]) -> Option<usize>,
 ) -> impl Fn(&[T]) -> Option<usize> {
@@ -0,0 +1,162 @@
+//! Showing where a shaper would draw dotted circles in a document.
+
+use std::io::{self, BufRead};
+
+use corpus::{dotted_circle, pattern, segment_with, Backend, Normalization, Script, DOTTED_CIRCLE};
+
+#[derive(Copy, Clone)]
+pub enum PreviewAs {
+    Text,
+    Html,
+}
+
+const HTML_HEAD: &str = "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Dotted circles</title>
+<style>
+pre { white-space: pre-wrap; font-family: serif; font-size: 150%; }
+mark { background: #fc6; }
+</style>
+</head>
+<body>
+<pre>";
+
+const HTML_TAIL: &str = "</pre>
+</body>
+</html>";
+
+/// Reads a document from `input` and prints it with a dotted circle wherever a shaper would
+/// insert one, either as text or as an HTML page that highlights the broken syllables.
+///
+/// The number of dotted circles and of lines with any goes to stderr.
+pub fn preview(
+    input: impl BufRead,
+    script: Script,
+    norm: Normalization,
+    backend: Backend,
+    format: PreviewAs,
+) -> io::Result<()> {
+    if let PreviewAs::Html = format {
+        println!("{}", HTML_HEAD);
+    }
+    let (mut circles, mut lines, mut lines_with_circles) = (0, 0, 0);
+    for (lineno, line) in input.lines().enumerate() {
+        let line = line?;
+        let text = norm.normalize(&line);
+        let (out, found) = preview_line(&text, lineno, script, backend, format);
+        println!("{}", out);
+        circles += found;
+        lines += 1;
+        if found > 0 {
+            lines_with_circles += 1;
+        }
+    }
+    if let PreviewAs::Html = format {
+        println!("{}", HTML_TAIL);
+    }
+    eprintln!(
+        "{} dotted circles on {} of {} lines",
+        circles, lines_with_circles, lines
+    );
+    Ok(())
+}
+
+/// Returns `text` with the dotted circles added, and how many there are.
+///
+/// The text between the clusters, such as the numbers the segmenter skips, is kept as is.
+fn preview_line(
+    text: &str,
+    lineno: usize,
+    script: Script,
+    backend: Backend,
+    format: PreviewAs,
+) -> (String, usize) {
+    let mut out = String::new();
+    let mut circles = 0;
+    let mut end = 0;
+    for cluster in segment_with(text, script, backend) {
+        push_text(&mut out, &text[end..cluster.range.start], format);
+        end = cluster.range.end;
+        let offset = match dotted_circle(&cluster, script) {
+            Some(offset) => offset,
+            None => {
+                push_text(&mut out, cluster.text, format);
+                continue;
+            }
+        };
+        circles += 1;
+        let (before, after) = cluster.text.split_at(offset - cluster.range.start);
+        if let PreviewAs::Html = format {
+            let column = text[..cluster.range.start].chars().count() + 1;
+            out.push_str(&format!(
+                "<mark title=\"line {} column {}: {}\">",
+                lineno + 1,
+                column,
+                pattern(cluster.text, script)
+            ));
+        }
+        push_text(&mut out, before, format);
+        out.push(DOTTED_CIRCLE);
+        push_text(&mut out, after, format);
+        if let PreviewAs::Html = format {
+            out.push_str("</mark>");
+        }
+    }
+    push_text(&mut out, &text[end..], format);
+    (out, circles)
+}
+
+fn push_text(out: &mut String, s: &str, format: PreviewAs) {
+    match format {
+        PreviewAs::Text => out.push_str(s),
+        PreviewAs::Html => {
+            for ch in s.chars() {
+                match ch {
+                    '&' => out.push_str("&amp;"),
+                    '<' => out.push_str("&lt;"),
+                    '>' => out.push_str("&gt;"),
+                    '"' => out.push_str("&quot;"),
+                    _ => out.push(ch),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{preview_line, PreviewAs};
+    use corpus::{Backend, Script};
+
+    #[test]
+    fn text_is_kept() {
+        let cases = [
+            ("कि २०१९ में ि", "कि २०१९ में ◌ि"),
+            ("number ५ ok", "number ५ ok"),
+            ("२ि", "२◌ि"),
+            ("", ""),
+        ];
+        for &(text, expected) in &cases {
+            let (out, _) = preview_line(text, 0, Script::Devanagari, Backend::Dfa, PreviewAs::Text);
+            assert_eq!(out, expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn html_is_escaped() {
+        let (out, circles) = preview_line(
+            "<ि> ५",
+            0,
+            Script::Devanagari,
+            Backend::Dfa,
+            PreviewAs::Html,
+        );
+        assert_eq!(circles, 1);
+        assert_eq!(
+            out,
+            "&lt;<mark title=\"line 1 column 2: VowelDependent(Left)\">◌ि</mark>&gt; ५"
+        );
+    }
+}
@@ -0,0 +1,304 @@
+//! The order in which shapers put the characters of a syllable for display, as the OpenType
+//! shaping documents describe it.
+//!
+//! Before looking up glyphs, shapers move pre-base matras before the consonants, the reph
+//! after the base and so on. `reorder` does the same, so that its output can be compared
+//! with that of a shaper.
+
+use super::indic::{self, MarkPlacementSubclass, ShapingClass};
+use super::{indic_character, myanmar, Script, DOTTED_CIRCLE};
+
+/// Where a character of a syllable goes, in visual order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Position {
+    PreBaseMatra,
+    /// A consonant after the base that is drawn before it, such as the Malayalam Ra or the
+    /// Myanmar medial ra
+    PreBaseReordering,
+    /// A consonant before the base, usually drawn as a half form
+    PreBaseConsonant,
+    Base,
+    /// Right after the base, where the reph goes in Oriya, Malayalam and Sinhala, and the
+    /// kinzi in Myanmar
+    AfterMain,
+    AboveBase,
+    /// Where the reph goes in Gurmukhi
+    BeforeSub,
+    BelowBase,
+    /// Where the reph goes in Bengali
+    AfterSub,
+    /// Where the reph goes in Devanagari and Gujarati
+    BeforePost,
+    PostBase,
+    /// Where the reph goes in Tamil, Telugu and Kannada
+    AfterPost,
+    /// Bindus, visargas and other syllable modifiers
+    Final,
+}
+
+impl Position {
+    pub fn name(self) -> &'static str {
+        match self {
+            Position::PreBaseMatra => "pre-base-matra",
+            Position::PreBaseReordering => "pre-base-reordering",
+            Position::PreBaseConsonant => "pre-base",
+            Position::Base => "base",
+            Position::AfterMain => "after-main",
+            Position::AboveBase => "above-base",
+            Position::BeforeSub => "before-sub",
+            Position::BelowBase => "below-base",
+            Position::AfterSub => "after-sub",
+            Position::BeforePost => "before-post",
+            Position::PostBase => "post-base",
+            Position::AfterPost => "after-post",
+            Position::Final => "final",
+        }
+    }
+}
+
+/// Puts the characters of a syllable in the order a shaper displays them, each with its
+/// position, or returns `None` for scripts that are not reordered.
+///
+/// Split matras are split into their parts as HarfBuzz splits them. A syllable without a
+/// base, as matched by the broken syllable rule, gets a dotted circle as its base.
+pub fn reorder(syllable: &str, script: Script) -> Option<Vec<(char, Position)>> {
+    match script {
+        _ if !reorders(script) => None,
+        Script::Myanmar => Some(myanmar::reorder(syllable)),
+        _ => Some(reorder_indic(syllable, script)),
+    }
+}
+
+/// Whether `reorder` reorders the syllables of `script`.
+pub fn reorders(script: Script) -> bool {
+    !matches!(
+        script,
+        Script::Khmer
+            | Script::Balinese
+            | Script::Javanese
+            | Script::TaiTham
+            | Script::Chakma
+            | Script::Sharada
+            | Script::Tirhuta
+            | Script::Kawi
+    )
+}
+
+fn class(ch: char) -> Option<ShapingClass> {
+    indic_character(ch).0
+}
+
+fn base_capable(class: Option<ShapingClass>) -> bool {
+    use self::ShapingClass::*;
+
+    matches!(
+        class,
+        Some(
+            Consonant
+                | ConsonantDead
+                | ConsonantPlaceholder
+                | ConsonantWithStacker
+                | Placeholder
+                | DottedCircle
+                | VowelIndependent
+        )
+    )
+}
+
+fn reorder_indic(syllable: &str, script: Script) -> Vec<(char, Position)> {
+    use self::ShapingClass::*;
+
+    let mut chars = Vec::new();
+    // whether each character is what is left of a Sinhala split matra after its left part
+    let mut remainders = Vec::new();
+    for ch in syllable.chars() {
+        match split_matra(ch) {
+            Some(parts) => {
+                chars.extend(parts);
+                remainders.extend(parts.iter().map(|&part| part == ch));
+            }
+            None => {
+                chars.push(ch);
+                remainders.push(false);
+            }
+        }
+    }
+    if !chars.is_empty() && !chars.iter().any(|&ch| base_capable(class(ch))) {
+        let start = chars
+            .iter()
+            .take_while(|&&ch| class(ch) == Some(ConsonantPreRepha))
+            .count();
+        chars.insert(start, DOTTED_CIRCLE);
+        remainders.insert(start, false);
+    }
+    let reph = reph_len(&chars, script);
+
+    // the base is the last consonant without a below-base, post-base or pre-base form
+    let mut base = reph;
+    for i in (reph..chars.len()).rev() {
+        if !base_capable(class(chars[i])) {
+            continue;
+        }
+        base = i;
+        let after_virama = i > reph && class(chars[i - 1]) == Some(Virama);
+        if !after_virama || consonant_form(chars[i], script).is_none() {
+            break;
+        }
+    }
+
+    // consonants after the base take their form together with the virama before them
+    let form = |ch| consonant_form(ch, script).unwrap_or(Position::PostBase);
+    let mut previous = Position::Base;
+    let mut reordered: Vec<(char, Position)> = Vec::new();
+    for (i, &ch) in chars.iter().enumerate() {
+        let position = match indic_character(ch) {
+            _ if i < reph => reph_position(script),
+            (Some(ConsonantPreRepha), _) => reph_position(script),
+            (c, _) if base_capable(c) && i < base => Position::PreBaseConsonant,
+            (c, _) if base_capable(c) && i == base => Position::Base,
+            (c, _) if base_capable(c) => form(ch),
+            (Some(Virama), _)
+                if i >= base && chars.get(i + 1).is_some_and(|&c| base_capable(class(c))) =>
+            {
+                form(chars[i + 1])
+            }
+            (Some(VowelDependent), placement) if remainders[i] => remainder_position(placement),
+            (Some(VowelDependent), placement) => mark_position(placement, Position::PostBase),
+            (Some(ConsonantMedial), placement) => mark_position(placement, Position::BelowBase),
+            (
+                Some(Bindu | Visarga | Cantillation | GeminationMark | SyllableModifier | Avagraha),
+                _,
+            ) => Position::Final,
+            // nuktas, viramas and joiners go with the character before them
+            _ => previous,
+        };
+        reordered.push((ch, position));
+        previous = position;
+    }
+    // a stable sort, so characters in the same position stay in logical order
+    reordered.sort_by_key(|&(_, position)| position);
+    reordered
+}
+
+/// The parts of a split matra, as HarfBuzz decomposes them.
+///
+/// Most are the canonical decomposition. The Sinhala ones keep the whole matra after its
+/// left part instead, as their right parts have no code points of their own; the al-lakuna
+/// in the canonical decomposition of U+0DDA would be taken for a virama.
+fn split_matra(ch: char) -> Option<&'static [char]> {
+    let parts: &[char] = match ch {
+        '\u{09CB}' => &['\u{09C7}', '\u{09BE}'],
+        '\u{09CC}' => &['\u{09C7}', '\u{09D7}'],
+        '\u{0B48}' => &['\u{0B47}', '\u{0B56}'],
+        '\u{0B4B}' => &['\u{0B47}', '\u{0B3E}'],
+        '\u{0B4C}' => &['\u{0B47}', '\u{0B57}'],
+        '\u{0BCA}' => &['\u{0BC6}', '\u{0BBE}'],
+        '\u{0BCB}' => &['\u{0BC7}', '\u{0BBE}'],
+        '\u{0BCC}' => &['\u{0BC6}', '\u{0BD7}'],
+        '\u{0C48}' => &['\u{0C46}', '\u{0C56}'],
+        '\u{0CC0}' => &['\u{0CBF}', '\u{0CD5}'],
+        '\u{0CC7}' => &['\u{0CC6}', '\u{0CD5}'],
+        '\u{0CC8}' => &['\u{0CC6}', '\u{0CD6}'],
+        '\u{0CCA}' => &['\u{0CC6}', '\u{0CC2}'],
+        '\u{0CCB}' => &['\u{0CC6}', '\u{0CC2}', '\u{0CD5}'],
+        '\u{0D4A}' => &['\u{0D46}', '\u{0D3E}'],
+        '\u{0D4B}' => &['\u{0D47}', '\u{0D3E}'],
+        '\u{0D4C}' => &['\u{0D46}', '\u{0D57}'],
+        '\u{0DDA}' => &['\u{0DD9}', '\u{0DDA}'],
+        '\u{0DDC}' => &['\u{0DD9}', '\u{0DDC}'],
+        '\u{0DDD}' => &['\u{0DD9}', '\u{0DDD}'],
+        '\u{0DDE}' => &['\u{0DD9}', '\u{0DDE}'],
+        _ => return None,
+    };
+    Some(parts)
+}
+
+/// The number of characters at the start of `chars` that form a reph.
+fn reph_len(chars: &[char], script: Script) -> usize {
+    let class_at = |i: usize| chars.get(i).and_then(|&ch| class(ch));
+    let len = if class_at(0) == Some(ShapingClass::ConsonantPreRepha) {
+        1
+    } else if !chars.first().is_some_and(|&ch| indic::ra(ch))
+        || class_at(1) != Some(ShapingClass::Virama)
+    {
+        0
+    } else {
+        let zwj = class_at(2) == Some(ShapingClass::Joiner);
+        match script {
+            // Ra Virama only forms a reph with a ZWJ after it
+            Script::Telugu | Script::Sinhala if zwj => 3,
+            Script::Telugu | Script::Sinhala => 0,
+            // a ZWJ after Ra Virama asks for a half form instead
+            _ if zwj => 0,
+            // only the dot reph, or no reph at all
+            Script::Malayalam | Script::Tamil => 0,
+            _ => 2,
+        }
+    };
+    // a reph needs a base after it
+    if len > 0 && chars[len..].iter().any(|&ch| base_capable(class(ch))) {
+        len
+    } else {
+        0
+    }
+}
+
+fn reph_position(script: Script) -> Position {
+    match script {
+        Script::Devanagari | Script::Gujarati => Position::BeforePost,
+        Script::Bengali => Position::AfterSub,
+        Script::Gurmukhi => Position::BeforeSub,
+        Script::Oriya | Script::Malayalam | Script::Sinhala => Position::AfterMain,
+        _ => Position::AfterPost,
+    }
+}
+
+/// The form a consonant takes after a virama that follows the base, if it has one.
+fn consonant_form(ch: char, script: Script) -> Option<Position> {
+    let below = Some(Position::BelowBase);
+    let post = Some(Position::PostBase);
+    match (script, ch) {
+        (Script::Devanagari, '\u{0930}') => below,
+        (Script::Bengali, '\u{09AC}' | '\u{09B0}' | '\u{09F0}') => below,
+        (Script::Bengali, '\u{09AF}' | '\u{09DF}') => post,
+        (Script::Gurmukhi, '\u{0A30}' | '\u{0A35}' | '\u{0A39}') => below,
+        (Script::Gurmukhi, '\u{0A2F}') => post,
+        (Script::Gujarati, '\u{0AB0}') => below,
+        (Script::Oriya, '\u{0B2F}' | '\u{0B5F}') => post,
+        (Script::Oriya, _) | (Script::Telugu, _) | (Script::Kannada, _)
+            if class(ch) == Some(ShapingClass::Consonant) =>
+        {
+            below
+        }
+        (Script::Malayalam, '\u{0D30}') => Some(Position::PreBaseReordering),
+        (Script::Malayalam, '\u{0D2F}' | '\u{0D35}') => post,
+        (Script::Malayalam, '\u{0D32}') => below,
+        (Script::Sinhala, '\u{0DBB}') => below,
+        (Script::Sinhala, '\u{0DBA}') => post,
+        _ => None,
+    }
+}
+
+/// Where the rest of a Sinhala split matra goes, which is its placement without the left.
+fn remainder_position(placement: Option<MarkPlacementSubclass>) -> Position {
+    match placement {
+        Some(MarkPlacementSubclass::TopAndLeftPosition) => Position::AboveBase,
+        _ => Position::PostBase,
+    }
+}
+
+fn mark_position(placement: Option<MarkPlacementSubclass>, default: Position) -> Position {
+    use self::MarkPlacementSubclass::*;
+
+    match placement {
+        // split matras that could not be decomposed go where their left part goes
+        Some(
+            LeftPosition | LeftAndRightPosition | TopAndLeftPosition | TopLeftAndRightPosition,
+        ) => Position::PreBaseMatra,
+        Some(TopPosition) => Position::AboveBase,
+        Some(BottomPosition | TopAndBottomPosition) => Position::BelowBase,
+        Some(RightPosition | TopAndRightPosition) => Position::PostBase,
+        Some(Overstruck) => Position::AfterMain,
+        None => default,
+    }
+}
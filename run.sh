@@ -1,8 +1,9 @@
 #! /bin/bash
 
-for s in bn gu hi kn ml or pa si ta te my ; do
+for s in bn gu hi kn ml or pa si ta te my km ; do
     echo $s syllables
     cat words/$s.* | target/release/syllables $s > data/syl.$s
     grep -v ^bad data/syl.$s > data/good.$s
     grep ^bad data/syl.$s > data/bad.$s
+    cat words/$s.* | target/release/syllables $s causes | grep -v ^bad > data/causes.$s
 done
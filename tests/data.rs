@@ -0,0 +1,99 @@
+//! Regression tests that check the segmenter against the syllables recorded in `data/`.
+//!
+//! Every line of `data/good.SCRIPT` must be a single cluster, and every cluster recorded in
+//! `data/bad.SCRIPT` must still be broken.
+
+extern crate corpus;
+
+use std::fs;
+use std::path::PathBuf;
+
+use corpus::{get_script, parse_bad_line, segment, Script};
+
+/// Scripts with data files, by the codes used in their names
+const SCRIPTS: &[&str] = &[
+    "bn", "gu", "hi", "km", "kn", "ml", "my", "or", "pa", "si", "ta", "te",
+];
+
+/// Number of failing lines shown for each script
+const EXAMPLES: usize = 10;
+
+fn read_data(name: &str) -> Option<String> {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("data");
+    path.push(name);
+    fs::read_to_string(path).ok()
+}
+
+fn script(code: &str) -> Script {
+    get_script(code).unwrap_or_else(|| panic!("unknown script {}", code))
+}
+
+/// Checks each line of a data file, printing a summary of the failures for each script and
+/// panicking if there were any.
+fn check_data(prefix: &str, check: impl Fn(&str, Script) -> bool) {
+    let mut failed_scripts = Vec::new();
+    for code in SCRIPTS {
+        let text = match read_data(&format!("{}.{}", prefix, code)) {
+            Some(text) => text,
+            None => continue,
+        };
+        let script = script(code);
+        let lines: Vec<&str> = text.lines().filter(|line| !line.is_empty()).collect();
+        let failures: Vec<&str> = lines
+            .iter()
+            .cloned()
+            .filter(|line| !check(line, script))
+            .collect();
+        if failures.is_empty() {
+            continue;
+        }
+        println!(
+            "{}.{}: {} of {} lines regressed",
+            prefix,
+            code,
+            failures.len(),
+            lines.len()
+        );
+        for line in failures.iter().take(EXAMPLES) {
+            println!("    {}", line);
+        }
+        if failures.len() > EXAMPLES {
+            println!("    ...");
+        }
+        failed_scripts.push(*code);
+    }
+    assert!(
+        failed_scripts.is_empty(),
+        "regressions in {} for {}",
+        prefix,
+        failed_scripts.join(", ")
+    );
+}
+
+#[test]
+fn good_syllables_are_single_clusters() {
+    check_data("good", |line, script| {
+        let clusters: Vec<_> = segment(line, script).collect();
+        clusters.len() == 1 && !clusters[0].broken && clusters[0].range == (0..line.len())
+    });
+}
+
+#[test]
+fn bad_clusters_are_still_broken() {
+    check_data("bad", |line, script| {
+        let bad = parse_bad_line(line).unwrap_or_else(|| panic!("bad line: {}", line));
+        let mut clusters = segment(&bad.text, script);
+        clusters.any(|c| c.range.start == bad.start && c.broken)
+    });
+}
+
+#[test]
+fn bad_line_formats_have_the_same_character() {
+    let old = parse_bad_line(r#"bad: "\u{34a}।" [U+34A] + [Danda] in line: [Ra] + [U+34A]"#);
+    let new = parse_bad_line(
+        r#"bad: "र\u{34a}" U+034A (latin-mark) count 1 first at line 1 column 1 byte 3: x"#,
+    );
+    assert_eq!(old.unwrap().character(), '\u{34A}');
+    assert_eq!(new.unwrap().character(), '\u{34A}');
+}
@@ -0,0 +1,113 @@
+//! Differential tests that check the automaton finds the same clusters as the combinators.
+
+extern crate corpus;
+
+use std::fs;
+use std::path::PathBuf;
+
+use corpus::{get_script, indic_character, parse_bad_line, segment_with, Backend, Script};
+
+/// Indic scripts with data files, by the codes used in their names
+const SCRIPTS: &[&str] = &["bn", "gu", "hi", "kn", "ml", "or", "pa", "si", "ta", "te"];
+
+/// Number of differences shown
+const EXAMPLES: usize = 10;
+
+/// Number of random texts tried
+const RANDOM_TEXTS: usize = 100_000;
+
+/// Longest random text, in characters
+const RANDOM_LEN: usize = 12;
+
+/// The clusters found by each backend, as text and kind, if they differ.
+fn compare(text: &str, script: Script) -> Option<String> {
+    let clusters = |backend| -> Vec<_> {
+        segment_with(text, script, backend)
+            .map(|cluster| (cluster.text, cluster.kind, cluster.broken))
+            .collect()
+    };
+    let expected = clusters(Backend::Combinators);
+    let found = clusters(Backend::Dfa);
+    if found == expected {
+        return None;
+    }
+    Some(format!(
+        "{:?}:\n    combinators {:?}\n    dfa         {:?}",
+        text, expected, found
+    ))
+}
+
+fn check(texts: &[String], script: Script) {
+    let differences: Vec<String> = texts
+        .iter()
+        .filter_map(|text| compare(text, script))
+        .collect();
+    for difference in differences.iter().take(EXAMPLES) {
+        println!("{}", difference);
+    }
+    assert!(
+        differences.is_empty(),
+        "{:?}: {} of {} texts are segmented differently",
+        script,
+        differences.len(),
+        texts.len()
+    );
+}
+
+#[test]
+fn same_clusters_in_data() {
+    for code in SCRIPTS {
+        let script = get_script(code).unwrap();
+        let mut texts = Vec::new();
+        for prefix in &["good", "bad"] {
+            let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+            path.push("data");
+            path.push(format!("{}.{}", prefix, code));
+            if let Ok(data) = fs::read_to_string(path) {
+                texts.extend(data.lines().map(|line| {
+                    parse_bad_line(line).map_or_else(|| line.to_string(), |bad| bad.text)
+                }));
+            }
+        }
+        check(&texts, script);
+    }
+}
+
+/// A small generator of pseudo-random numbers, so that failures can be reproduced.
+struct XorShift(u64);
+
+impl XorShift {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// Random sequences of characters of every shaping class, to reach the corners of the
+/// grammar that real text does not.
+#[test]
+fn same_clusters_in_random_text() {
+    // a character of each shaping class, some Ra and something outside of the tables
+    let mut alphabet: Vec<char> = vec!['\u{0930}', '\u{09F0}', '\u{0D30}', ' '];
+    let mut classes = Vec::new();
+    for ch in (0x0900..0x10000).filter_map(std::char::from_u32) {
+        if let (Some(class), _) = indic_character(ch) {
+            if !classes.contains(&class) {
+                classes.push(class);
+                alphabet.push(ch);
+            }
+        }
+    }
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+    let texts: Vec<String> = (0..RANDOM_TEXTS)
+        .map(|_| {
+            let len = 1 + rng.below(RANDOM_LEN);
+            (0..len)
+                .map(|_| alphabet[rng.below(alphabet.len())])
+                .collect()
+        })
+        .collect();
+    check(&texts, Script::Devanagari);
+}
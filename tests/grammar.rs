@@ -0,0 +1,525 @@
+//! Conformance tests for the cluster grammars.
+//!
+//! Each case is a hand-written example of one production of the grammars in the OpenType
+//! shaping documents, split into the clusters it should match, with the kind of each.
+
+extern crate corpus;
+
+use corpus::Syllable::{Broken, Consonant, Standalone, Symbol, Vowel};
+use corpus::{segment, Script, Syllable};
+
+/// A grammar rule and the clusters of an example of it
+type Case = (&'static str, &'static [(&'static str, Syllable)]);
+
+// https://github.com/n8willis/opentype-shaping-documents/blob/master/opentype-shaping-devanagari.md
+const DEVANAGARI: &[Case] = &[
+    ("C", &[("\u{915}", Consonant)]),
+    ("C: Ra", &[("\u{930}", Consonant)]),
+    ("Cn: nukta", &[("\u{915}\u{93C}", Consonant)]),
+    ("Cn: ZWJ", &[("\u{915}\u{200D}", Consonant)]),
+    ("Cn: ZWJ nukta", &[("\u{915}\u{200D}\u{93C}", Consonant)]),
+    ("halant_group", &[("\u{915}\u{94D}\u{937}", Consonant)]),
+    (
+        "halant_group: Z",
+        &[("\u{915}\u{200D}\u{94D}\u{937}", Consonant)],
+    ),
+    (
+        "halant_group: ZWJ after halant",
+        &[("\u{915}\u{94D}\u{200D}\u{937}", Consonant)],
+    ),
+    (
+        "halant_group: Ra halant",
+        &[("\u{930}\u{94D}\u{915}", Consonant)],
+    ),
+    (
+        "halant_group: four times",
+        &[(
+            "\u{938}\u{94D}\u{924}\u{94D}\u{930}\u{94D}\u{92F}\u{94D}\u{935}",
+            Consonant,
+        )],
+    ),
+    (
+        "halant_group: at most four times",
+        &[
+            (
+                "\u{915}\u{94D}\u{915}\u{94D}\u{915}\u{94D}\u{915}\u{94D}\u{915}\u{94D}",
+                Consonant,
+            ),
+            ("\u{915}", Consonant),
+        ],
+    ),
+    (
+        "final_halant_group: halant",
+        &[("\u{915}\u{94D}", Consonant)],
+    ),
+    (
+        "final_halant_group: halant ZWNJ",
+        &[
+            ("\u{915}\u{94D}\u{200C}", Consonant),
+            ("\u{937}", Consonant),
+        ],
+    ),
+    (
+        "final_halant_group: halant ZWJ",
+        &[("\u{915}\u{94D}\u{200D}", Consonant)],
+    ),
+    ("matra_group", &[("\u{915}\u{93F}", Consonant)]),
+    ("matra_group: Z", &[("\u{915}\u{200C}\u{93F}", Consonant)]),
+    (
+        "matra_group: nukta halant",
+        &[("\u{915}\u{940}\u{93C}\u{94D}", Consonant)],
+    ),
+    (
+        "matra_group: forced rakar",
+        &[("\u{915}\u{93E}\u{200D}\u{94D}\u{200D}\u{930}", Consonant)],
+    ),
+    (
+        "matra_group: twice",
+        &[("\u{915}\u{93E}\u{947}", Consonant)],
+    ),
+    // Deviates from the shaping documents, see match_halant_or_matra_group and
+    // https://github.com/n8willis/opentype-shaping-documents/issues/72
+    (
+        "halant_or_matra_group: no matras after final halant",
+        &[("\u{915}\u{94D}", Consonant), ("\u{93F}", Broken)],
+    ),
+    (
+        "halant_or_matra_group: four matra groups",
+        &[("\u{915}\u{93E}\u{947}\u{948}\u{94B}", Consonant)],
+    ),
+    (
+        "halant_or_matra_group: at most four matra groups",
+        &[
+            ("\u{915}\u{93E}\u{947}\u{948}\u{94B}", Consonant),
+            ("\u{94C}", Broken),
+        ],
+    ),
+    ("syllable_tail: SM", &[("\u{915}\u{902}", Consonant)]),
+    (
+        "syllable_tail: SM SM",
+        &[("\u{915}\u{93F}\u{902}\u{903}", Consonant)],
+    ),
+    (
+        "syllable_tail: Z SM",
+        &[("\u{915}\u{200D}\u{902}", Consonant)],
+    ),
+    (
+        "syllable_tail: SM SM ZWNJ",
+        &[("\u{915}\u{902}\u{903}\u{200C}", Consonant)],
+    ),
+    ("syllable_tail: VD", &[("\u{915}\u{951}", Consonant)]),
+    (
+        "syllable_tail: VD three times",
+        &[("\u{915}\u{902}\u{951}\u{952}\u{951}", Consonant)],
+    ),
+    (
+        "consonant_syllable: sequence",
+        &[
+            ("\u{915}\u{93F}", Consonant),
+            ("\u{924}\u{93E}", Consonant),
+            ("\u{92C}", Consonant),
+        ],
+    ),
+    ("vowel_syllable", &[("\u{905}", Vowel)]),
+    ("vowel_syllable: nukta", &[("\u{907}\u{93C}", Vowel)]),
+    ("vowel_syllable: ZWJ", &[("\u{905}\u{200D}", Vowel)]),
+    ("vowel_syllable: Reph", &[("\u{930}\u{94D}\u{90B}", Vowel)]),
+    ("vowel_syllable: matra_group", &[("\u{905}\u{947}", Vowel)]),
+    (
+        "vowel_syllable: syllable_tail",
+        &[("\u{906}\u{902}", Vowel)],
+    ),
+    (
+        "standalone_syllable: placeholder",
+        &[("\u{A0}\u{93F}", Standalone)],
+    ),
+    (
+        "standalone_syllable: dotted circle",
+        &[("\u{25CC}\u{94D}", Standalone)],
+    ),
+    (
+        "standalone_syllable: Reph dotted circle",
+        &[("\u{930}\u{94D}\u{25CC}", Standalone)],
+    ),
+    ("symbol_syllable", &[("\u{93D}", Symbol)]),
+    (
+        "symbol_syllable: syllable_tail",
+        &[("\u{93D}\u{902}", Symbol)],
+    ),
+    ("broken_syllable: matra", &[("\u{93F}", Broken)]),
+    (
+        "broken_syllable: nukta matra",
+        &[("\u{93C}\u{940}", Broken)],
+    ),
+    (
+        "broken_syllable: halant_group",
+        &[("\u{94D}\u{937}\u{93E}", Broken)],
+    ),
+    (
+        "broken_syllable: Reph",
+        &[("\u{930}\u{94D}\u{93E}", Broken)],
+    ),
+];
+
+const BENGALI: &[Case] = &[
+    (
+        "vowel_syllable: halant_group Cn",
+        &[("\u{985}\u{9CD}\u{9AF}\u{9BE}", Vowel)],
+    ),
+    ("symbol_syllable", &[("\u{9F2}", Symbol)]),
+];
+
+const GURMUKHI: &[Case] = &[
+    ("medial_group", &[("\u{A15}\u{A75}", Consonant)]),
+    (
+        "medial_group: halant_or_matra_group",
+        &[("\u{A15}\u{A75}\u{A3F}", Consonant)],
+    ),
+    (
+        "syllable_tail: gemination mark",
+        &[("\u{A71}", Broken), ("\u{A15}\u{A71}", Consonant)],
+    ),
+];
+
+const KANNADA: &[Case] = &[(
+    "consonant_syllable: CS",
+    &[("\u{CF1}\u{C95}\u{CBF}", Consonant)],
+)];
+
+const MALAYALAM: &[Case] = &[
+    (
+        "consonant_syllable: Repha",
+        &[("\u{D4E}\u{D15}", Consonant)],
+    ),
+    ("C: dead consonant", &[("\u{D7A}", Consonant)]),
+    ("vowel_syllable: Reph", &[("\u{D30}\u{D4D}\u{D07}", Vowel)]),
+];
+
+const GUJARATI: &[Case] = &[
+    ("Cn: nukta", &[("\u{A9C}\u{ABC}", Consonant)]),
+    (
+        "consonant_syllable: Reph",
+        &[("\u{AB0}\u{ACD}\u{A95}\u{ABF}", Consonant)],
+    ),
+    ("broken_syllable: matra", &[("\u{ABF}", Broken)]),
+];
+
+const ORIYA: &[Case] = &[
+    ("matra_group: split matra", &[("\u{B15}\u{B4C}", Consonant)]),
+    ("vowel_syllable: bindu", &[("\u{B05}\u{B01}", Vowel)]),
+    ("broken_syllable: matra", &[("\u{B3E}", Broken)]),
+];
+
+const TAMIL: &[Case] = &[
+    ("matra_group: split matra", &[("\u{B95}\u{BCA}", Consonant)]),
+    (
+        "halant_group",
+        &[("\u{B95}\u{BCD}\u{BB7}\u{BBF}", Consonant)],
+    ),
+    ("broken_syllable: halant", &[("\u{BCD}", Broken)]),
+];
+
+const TELUGU: &[Case] = &[
+    (
+        "halant_group: matra",
+        &[("\u{C15}\u{C4D}\u{C37}\u{C3F}", Consonant)],
+    ),
+    ("Cn: nukta", &[("\u{C15}\u{C3C}", Consonant)]),
+    ("broken_syllable: matra", &[("\u{C3E}", Broken)]),
+];
+
+const SINHALA: &[Case] = &[
+    ("matra_group: split matra", &[("\u{D9A}\u{DDA}", Consonant)]),
+    (
+        "matra_group: split matra with a right part",
+        &[("\u{D9A}\u{DDC}", Consonant)],
+    ),
+    (
+        "halant_group: ZWJ",
+        &[("\u{D9A}\u{DCA}\u{200D}\u{DBA}", Consonant)],
+    ),
+    (
+        "syllable_tail: candrabindu",
+        &[("\u{D9A}\u{D81}", Consonant)],
+    ),
+    ("broken_syllable: matra", &[("\u{DCF}", Broken)]),
+];
+
+// https://github.com/n8willis/opentype-shaping-documents/blob/master/opentype-shaping-khmer.md
+const KHMER: &[Case] = &[
+    ("cn", &[("\u{1780}", Consonant)]),
+    ("cn: V", &[("\u{17A5}", Consonant)]),
+    ("cn: Robatic", &[("\u{1780}\u{17CC}", Consonant)]),
+    (
+        "cn: joiner Robatic",
+        &[("\u{1794}\u{200C}\u{17CA}", Consonant)],
+    ),
+    (
+        "consonant_syllable: placeholder",
+        &[("\u{A0}\u{17B6}", Consonant)],
+    ),
+    (
+        "consonant_syllable: dotted circle",
+        &[("\u{25CC}\u{17BB}", Consonant)],
+    ),
+    (
+        "broken_cluster: Coeng cn",
+        &[("\u{179F}\u{17D2}\u{178F}", Consonant)],
+    ),
+    (
+        "broken_cluster: Coeng cn twice",
+        &[("\u{179F}\u{17D2}\u{178F}\u{17D2}\u{179A}", Consonant)],
+    ),
+    (
+        "broken_cluster: final Coeng",
+        &[("\u{1780}\u{17D2}", Consonant)],
+    ),
+    ("matra_group: VPre", &[("\u{1780}\u{17C1}", Consonant)]),
+    ("matra_group: VBlw", &[("\u{1780}\u{17BB}", Consonant)]),
+    ("matra_group: VAbv", &[("\u{1780}\u{17B7}", Consonant)]),
+    ("matra_group: VPst", &[("\u{1780}\u{17B6}", Consonant)]),
+    (
+        "matra_group: split vowel",
+        &[("\u{1780}\u{17BE}", Consonant)],
+    ),
+    (
+        "matra_group: joiner VAbv",
+        &[("\u{1780}\u{200C}\u{17B7}", Consonant)],
+    ),
+    (
+        "matra_group: xgroup",
+        &[("\u{1780}\u{17BB}\u{17C6}\u{17B6}", Consonant)],
+    ),
+    (
+        "syllable_tail: Coeng c",
+        &[("\u{1780}\u{17B6}\u{17D2}\u{1799}", Consonant)],
+    ),
+    (
+        "syllable_tail: ygroup",
+        &[("\u{1780}\u{17B6}\u{17C7}", Consonant)],
+    ),
+    (
+        "syllable_tail: xgroup ygroup",
+        &[("\u{1780}\u{17CB}\u{17C8}", Consonant)],
+    ),
+    (
+        "consonant_syllable: sequence",
+        &[
+            ("\u{1781}\u{17D2}\u{1798}\u{17C2}", Consonant),
+            ("\u{179A}", Consonant),
+        ],
+    ),
+    ("broken_cluster: matra", &[("\u{17B6}", Broken)]),
+    ("broken_cluster: Coeng", &[("\u{17D2}\u{1780}", Broken)]),
+    ("standalone", &[("\u{17D4}", Symbol)]),
+];
+
+// https://github.com/n8willis/opentype-shaping-documents/blob/master/opentype-shaping-myanmar.md
+const MYANMAR: &[Case] = &[
+    ("C", &[("\u{1000}", Consonant)]),
+    ("vowel", &[("\u{1021}", Consonant)]),
+    ("G: generic base", &[("\u{25CC}\u{102C}", Consonant)]),
+    ("G: digit", &[("\u{1041}", Consonant)]),
+    ("G: punc", &[("\u{104B}", Consonant)]),
+    ("K", &[("\u{1004}\u{103A}\u{1039}\u{1000}", Consonant)]),
+    ("vs", &[("\u{1000}\u{FE00}", Consonant)]),
+    ("halant C", &[("\u{1019}\u{1039}\u{1018}", Consonant)]),
+    ("halant vowel", &[("\u{1000}\u{1039}\u{1021}", Consonant)]),
+    ("Tail: halant", &[("\u{1000}\u{1039}", Consonant)]),
+    ("Tcomplex: asat", &[("\u{1000}\u{103A}", Consonant)]),
+    ("Tcomplex: Z", &[("\u{1000}\u{200C}", Consonant)]),
+    ("Tcomplex: sm", &[("\u{1000}\u{1038}", Consonant)]),
+    ("Med: my", &[("\u{1000}\u{103B}", Consonant)]),
+    ("Med: mr", &[("\u{1000}\u{103C}", Consonant)]),
+    ("Med: mw mh", &[("\u{1000}\u{103D}\u{103E}", Consonant)]),
+    ("Med: mh ml", &[("\u{1000}\u{103E}\u{1060}", Consonant)]),
+    (
+        "Med: my mw asat",
+        &[("\u{1000}\u{103B}\u{103D}\u{103A}", Consonant)],
+    ),
+    ("Med: mr mw", &[("\u{1000}\u{103C}\u{103D}", Consonant)]),
+    ("Vmain: matrapre", &[("\u{1000}\u{1031}", Consonant)]),
+    (
+        "Vmain: matraabove matrabelow",
+        &[("\u{1000}\u{102D}\u{102F}", Consonant)],
+    ),
+    ("Vmain: a db", &[("\u{1000}\u{1032}\u{1037}", Consonant)]),
+    ("Vmain: db asat", &[("\u{1000}\u{1037}\u{103A}", Consonant)]),
+    ("Vpost", &[("\u{1000}\u{102C}", Consonant)]),
+    (
+        "Vpost: asat",
+        &[("\u{1000}\u{1031}\u{102C}\u{103A}", Consonant)],
+    ),
+    ("Vpost: mh", &[("\u{1000}\u{102B}\u{103E}", Consonant)]),
+    (
+        "Vpost: a db",
+        &[("\u{1000}\u{102C}\u{1036}\u{1037}", Consonant)],
+    ),
+    ("Pwo", &[("\u{1000}\u{1063}", Consonant)]),
+    (
+        "Pwo: a db asat",
+        &[("\u{1000}\u{1069}\u{1036}\u{1037}\u{103A}", Consonant)],
+    ),
+    (
+        "Tcomplex: sequence",
+        &[
+            ("\u{1019}\u{103C}", Consonant),
+            ("\u{1014}\u{103A}", Consonant),
+            ("\u{1019}\u{102C}", Consonant),
+        ],
+    ),
+    (
+        "Tcomplex: everything",
+        &[(
+            "\u{1000}\u{103A}\u{103B}\u{103D}\u{1031}\u{102D}\u{102F}\u{1036}\u{102C}\u{1063}\u{1038}\u{200C}",
+            Consonant,
+        )],
+    ),
+    ("standalone", &[("\u{104F}", Broken)]),
+    ("broken: matra", &[("\u{102C}", Broken)]),
+];
+
+// https://learn.microsoft.com/en-us/typography/script-development/use
+const JAVANESE: &[Case] = &[
+    ("standard_cluster", &[("\u{A98F}", Consonant)]),
+    (
+        "dependent_vowels",
+        &[("\u{A98F}\u{A9BA}\u{A9B6}\u{A9B8}\u{A9B4}", Consonant)],
+    ),
+    (
+        "medial_consonants",
+        &[("\u{A98F}\u{A9BF}\u{A9B6}", Consonant)],
+    ),
+    (
+        "vowel_modifiers",
+        &[("\u{A98F}\u{A9B6}\u{A981}", Consonant)],
+    ),
+    ("final_consonants", &[("\u{A98F}\u{A982}", Consonant)]),
+    (
+        "virama_terminated_cluster",
+        &[("\u{A98F}\u{A9C0}", Consonant)],
+    ),
+    (
+        "consonant_modifiers: h B",
+        &[("\u{A98F}\u{A9C0}\u{A9B1}\u{A9B8}", Consonant)],
+    ),
+    ("symbol_cluster", &[("\u{A9C1}", Symbol)]),
+    ("broken_cluster", &[("\u{A9B6}", Broken)]),
+];
+
+const BALINESE: &[Case] = &[
+    (
+        "consonant_modifiers: CMAbv",
+        &[("\u{1B13}\u{1B34}", Consonant)],
+    ),
+    (
+        "final_consonants: FAbv",
+        &[("\u{1B13}\u{1B3E}\u{1B35}\u{1B03}", Consonant)],
+    ),
+    ("symbol_cluster_tail", &[("\u{1B5A}\u{1B6B}", Symbol)]),
+    ("broken_cluster: tail", &[("\u{1B44}\u{1B13}", Broken)]),
+];
+
+const TAI_THAM: &[Case] = &[
+    (
+        "sakot_terminated_cluster",
+        &[("\u{1A20}\u{1A60}", Consonant)],
+    ),
+    (
+        "complex_syllable_middle: Sk B",
+        &[("\u{1A20}\u{1A60}\u{1A3F}", Consonant)],
+    ),
+    (
+        "medial_consonants: MPre",
+        &[("\u{1A20}\u{1A55}", Consonant)],
+    ),
+];
+
+const CHAKMA: &[Case] = &[
+    ("dependent_vowels", &[("\u{11107}\u{11127}", Consonant)]),
+    (
+        "virama_terminated_cluster",
+        &[("\u{11107}\u{11133}", Consonant)],
+    ),
+    ("broken_cluster", &[("\u{11127}", Broken)]),
+];
+
+const SHARADA: &[Case] = &[
+    ("dependent_vowels", &[("\u{11191}\u{111B3}", Consonant)]),
+    (
+        "consonant_modifiers: h B",
+        &[("\u{11191}\u{111C0}\u{111A7}", Consonant)],
+    ),
+    ("broken_cluster", &[("\u{111B3}", Broken)]),
+];
+
+const TIRHUTA: &[Case] = &[
+    (
+        "dependent_vowels: VPre",
+        &[("\u{1148F}\u{114B1}", Consonant)],
+    ),
+    (
+        "vowel_modifiers",
+        &[("\u{1148F}\u{114B0}\u{114C0}", Consonant)],
+    ),
+    ("broken_cluster", &[("\u{114B0}", Broken)]),
+];
+
+const KAWI: &[Case] = &[
+    ("dependent_vowels", &[("\u{11F12}\u{11F34}", Consonant)]),
+    (
+        "consonant_modifiers: h B",
+        &[("\u{11F12}\u{11F42}\u{11F13}", Consonant)],
+    ),
+    ("broken_cluster", &[("\u{11F34}", Broken)]),
+];
+
+const SCRIPTS: &[(Script, &[Case])] = &[
+    (Script::Devanagari, DEVANAGARI),
+    (Script::Bengali, BENGALI),
+    (Script::Gurmukhi, GURMUKHI),
+    (Script::Gujarati, GUJARATI),
+    (Script::Oriya, ORIYA),
+    (Script::Tamil, TAMIL),
+    (Script::Telugu, TELUGU),
+    (Script::Kannada, KANNADA),
+    (Script::Malayalam, MALAYALAM),
+    (Script::Sinhala, SINHALA),
+    (Script::Khmer, KHMER),
+    (Script::Myanmar, MYANMAR),
+    (Script::Javanese, JAVANESE),
+    (Script::Balinese, BALINESE),
+    (Script::TaiTham, TAI_THAM),
+    (Script::Chakma, CHAKMA),
+    (Script::Sharada, SHARADA),
+    (Script::Tirhuta, TIRHUTA),
+    (Script::Kawi, KAWI),
+];
+
+/// Returns the clusters and their kinds as found by `segment`, in the same form as a `Case`.
+fn clusters(text: &str, script: Script) -> Vec<(&str, Syllable)> {
+    segment(text, script)
+        .map(|cluster| (cluster.text, cluster.kind))
+        .collect()
+}
+
+#[test]
+fn grammar_rules() {
+    let mut failures = Vec::new();
+    for &(script, cases) in SCRIPTS {
+        for &(rule, expected) in cases {
+            let text: String = expected.iter().map(|&(s, _)| s).collect();
+            let found = clusters(&text, script);
+            if found != expected {
+                failures.push(format!(
+                    "{:?} {}:\n    expected {:?}\n    found    {:?}",
+                    script, rule, expected, found
+                ));
+            }
+        }
+    }
+    for failure in &failures {
+        println!("{}", failure);
+    }
+    assert!(failures.is_empty(), "{} rules failed", failures.len());
+}
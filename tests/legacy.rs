@@ -0,0 +1,86 @@
+//! Tests of finding text in legacy font encodings, and of converting Krutidev.
+
+extern crate corpus;
+
+use corpus::{detect_legacy, krutidev_to_unicode, Legacy, Script};
+
+#[test]
+fn krutidev_is_converted() {
+    let cases = [
+        ("Hkkjr", "\u{092D}\u{093E}\u{0930}\u{0924}"),
+        // vowel sign I is typed before the whole conjunct
+        ("fLFkfr", "\u{0938}\u{094D}\u{0925}\u{093F}\u{0924}\u{093F}"),
+        // reph is typed after the syllable, and after its vowel sign I
+        ("dk;Z", "\u{0915}\u{093E}\u{0930}\u{094D}\u{092F}"),
+        ("dhfrZ", "\u{0915}\u{0940}\u{0930}\u{094D}\u{0924}\u{093F}"),
+        ("vkSj ,d", "\u{0914}\u{0930} \u{090F}\u{0915}"),
+        ("c<+h", "\u{092C}\u{0922}\u{093C}\u{0940}"),
+        (
+            "ubZ fnYyh 2024 A",
+            "\u{0928}\u{0908} \u{0926}\u{093F}\u{0932}\u{094D}\u{0932}\u{0940} 2024 \u{0964}",
+        ),
+        // vowel sign I does not reach across a space
+        ("fd fd", "\u{0915}\u{093F} \u{0915}\u{093F}"),
+        // reph and vowel sign I stay as they are without a consonant to attach to
+        ("f", "\u{093F}"),
+        ("Z", "\u{0930}\u{094D}"),
+        ("", ""),
+    ];
+    for &(krutidev, unicode) in &cases {
+        assert_eq!(krutidev_to_unicode(krutidev), unicode, "{:?}", krutidev);
+    }
+}
+
+#[test]
+fn legacy_lines_are_detected() {
+    let lines = [
+        (
+            "Hkkjr ,d fo'kky ns'k gS A bldh jkt/kkuh ubZ fnYyh gS A",
+            Script::Devanagari,
+            Some(Legacy::Krutidev),
+        ),
+        (
+            "The quick brown fox jumps over the lazy dog.",
+            Script::Devanagari,
+            None,
+        ),
+        (
+            "\u{092D}\u{093E}\u{0930}\u{0924} \u{090F}\u{0915} \u{0926}\u{0947}\u{0936} \u{0939}\u{0948}\u{0964}",
+            Script::Devanagari,
+            None,
+        ),
+        (
+            "\u{00C3}\u{00D6}\u{00E3}\u{00D6}\u{00B8}\u{00FC}\u{0178}\u{00D6} \u{00D7}\u{00BE}\u{00D6}\u{00BF}\u{00D6}\u{00D6}",
+            Script::Devanagari,
+            Some(Legacy::FontEncoding),
+        ),
+        (
+            "\u{093F}\u{0915} \u{093F}\u{0916} \u{093F}\u{0917} \u{0915}\u{093E}",
+            Script::Devanagari,
+            Some(Legacy::Misencoded),
+        ),
+        (
+            "\u{103B}\u{1019}\u{1014}\u{1039}\u{1019}\u{102C}",
+            Script::Myanmar,
+            Some(Legacy::Zawgyi),
+        ),
+        // each encoding is only looked for in its own script
+        (
+            "\u{103B}\u{1019}\u{1014}\u{1039}\u{1019}\u{102C}",
+            Script::Devanagari,
+            None,
+        ),
+        ("Hkkjr ,d fo'kky ns'k gS A", Script::Myanmar, None),
+        // a line that is mostly Unicode is not reported for one Krutidev word
+        (
+            "Hkkjr \u{092D}\u{093E}\u{0930}\u{0924}",
+            Script::Devanagari,
+            None,
+        ),
+        ("", Script::Devanagari, None),
+        ("", Script::Myanmar, None),
+    ];
+    for &(line, script, expected) in &lines {
+        assert_eq!(detect_legacy(line, script), expected, "{:?}", line);
+    }
+}
@@ -0,0 +1,246 @@
+//! Tests of the reordering of syllables: Indic syllables written out by hand, and Myanmar
+//! syllables from `data/good.my`.
+
+extern crate corpus;
+
+use std::fs;
+use std::path::PathBuf;
+
+use corpus::{reorder, Position, Script};
+
+fn visual(syllable: &str, script: Script) -> String {
+    let reordered = reorder(syllable, script).expect("the script is reordered");
+    reordered.iter().map(|&(ch, _)| ch).collect()
+}
+
+fn good_syllables() -> Vec<String> {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("data");
+    path.push("good.my");
+    let data = fs::read_to_string(path).unwrap();
+    data.lines().map(String::from).collect()
+}
+
+// https://github.com/n8willis/opentype-shaping-documents/blob/master/opentype-shaping-indic-general.md
+#[test]
+fn reph() {
+    let cases = [
+        (
+            Script::Devanagari,
+            "\u{0930}\u{094D}\u{0915}\u{093F}",
+            "\u{093F}\u{0915}\u{0930}\u{094D}",
+        ),
+        // Bengali puts the reph after the below-base forms, before a post-base Ya
+        (
+            Script::Bengali,
+            "\u{09B0}\u{09CD}\u{0995}\u{09CD}\u{09AF}",
+            "\u{0995}\u{09B0}\u{09CD}\u{09CD}\u{09AF}",
+        ),
+        // Telugu needs a ZWJ for a reph, Kannada takes one without
+        (
+            Script::Telugu,
+            "\u{0C30}\u{0C4D}\u{200D}\u{0C15}",
+            "\u{0C15}\u{0C30}\u{0C4D}\u{200D}",
+        ),
+        (
+            Script::Telugu,
+            "\u{0C30}\u{0C4D}\u{0C15}",
+            "\u{0C30}\u{0C4D}\u{0C15}",
+        ),
+        (
+            Script::Kannada,
+            "\u{0CB0}\u{0CCD}\u{0C95}",
+            "\u{0C95}\u{0CB0}\u{0CCD}",
+        ),
+        (
+            Script::Kannada,
+            "\u{0CB0}\u{0CCD}\u{200D}\u{0C95}",
+            "\u{0CB0}\u{0CCD}\u{200D}\u{0C95}",
+        ),
+        // a reph on its own is not a base
+        (Script::Devanagari, "\u{0930}\u{094D}", "\u{0930}\u{094D}"),
+    ];
+    for &(script, syllable, expected) in &cases {
+        assert_eq!(visual(syllable, script), expected, "{:?}", syllable);
+    }
+}
+
+#[test]
+fn split_matras() {
+    let cases = [
+        (
+            Script::Malayalam,
+            "\u{0D15}\u{0D4A}",
+            "\u{0D46}\u{0D15}\u{0D3E}",
+        ),
+        (
+            Script::Malayalam,
+            "\u{0D15}\u{0D4C}",
+            "\u{0D46}\u{0D15}\u{0D57}",
+        ),
+        // the left part goes before a pre-base Ra too
+        (
+            Script::Malayalam,
+            "\u{0D15}\u{0D4D}\u{0D30}\u{0D4A}",
+            "\u{0D46}\u{0D4D}\u{0D30}\u{0D15}\u{0D3E}",
+        ),
+        (
+            Script::Oriya,
+            "\u{0B15}\u{0B4C}",
+            "\u{0B47}\u{0B15}\u{0B57}",
+        ),
+        (
+            Script::Tamil,
+            "\u{0B95}\u{0BCA}",
+            "\u{0BC6}\u{0B95}\u{0BBE}",
+        ),
+        (
+            Script::Tamil,
+            "\u{0B95}\u{0BCD}\u{0BB7}\u{0BCB}",
+            "\u{0BC7}\u{0B95}\u{0BCD}\u{0BB7}\u{0BBE}",
+        ),
+        // the Sinhala matras keep no al-lakuna of their own
+        (
+            Script::Sinhala,
+            "\u{0D9A}\u{0DDA}",
+            "\u{0DD9}\u{0D9A}\u{0DDA}",
+        ),
+        (
+            Script::Sinhala,
+            "\u{0D9A}\u{0DDC}",
+            "\u{0DD9}\u{0D9A}\u{0DDC}",
+        ),
+        // Kannada U+0CCB splits in three, none of them before the base
+        (
+            Script::Kannada,
+            "\u{0C95}\u{0CCB}",
+            "\u{0C95}\u{0CC6}\u{0CC2}\u{0CD5}",
+        ),
+    ];
+    for &(script, syllable, expected) in &cases {
+        assert_eq!(visual(syllable, script), expected, "{:?}", syllable);
+    }
+}
+
+#[test]
+fn sinhala_split_matra_positions() {
+    assert_eq!(
+        reorder("\u{0D9A}\u{0DDA}", Script::Sinhala),
+        Some(vec![
+            ('\u{0DD9}', Position::PreBaseMatra),
+            ('\u{0D9A}', Position::Base),
+            ('\u{0DDA}', Position::AboveBase),
+        ])
+    );
+    assert_eq!(
+        reorder("\u{0D9A}\u{0DDD}", Script::Sinhala),
+        Some(vec![
+            ('\u{0DD9}', Position::PreBaseMatra),
+            ('\u{0D9A}', Position::Base),
+            ('\u{0DDD}', Position::PostBase),
+        ])
+    );
+}
+
+#[test]
+fn shared_marks() {
+    // Vedic signs are written in several scripts and go last
+    assert_eq!(
+        visual("\u{0995}\u{09BF}\u{1CD0}", Script::Bengali),
+        "\u{09BF}\u{0995}\u{1CD0}"
+    );
+    assert_eq!(
+        visual(
+            "\u{0930}\u{094D}\u{0915}\u{1CDA}\u{093F}",
+            Script::Devanagari
+        ),
+        "\u{093F}\u{0915}\u{0930}\u{094D}\u{1CDA}"
+    );
+    // a ZWNJ stays where it was typed
+    assert_eq!(
+        visual("\u{0995}\u{200C}\u{09BF}", Script::Bengali),
+        "\u{09BF}\u{0995}\u{200C}"
+    );
+}
+
+#[test]
+fn dotted_circle() {
+    let cases = [
+        (Script::Devanagari, "\u{093F}", "\u{093F}\u{25CC}"),
+        (
+            Script::Devanagari,
+            "\u{093F}\u{0902}",
+            "\u{093F}\u{25CC}\u{0902}",
+        ),
+        (Script::Myanmar, "\u{25CC}\u{1031}", "\u{1031}\u{25CC}"),
+    ];
+    for &(script, syllable, expected) in &cases {
+        assert_eq!(visual(syllable, script), expected, "{:?}", syllable);
+    }
+}
+
+#[test]
+fn empty_syllable() {
+    assert_eq!(reorder("", Script::Devanagari), Some(vec![]));
+    assert_eq!(reorder("", Script::Myanmar), Some(vec![]));
+}
+
+#[test]
+fn unsupported_scripts() {
+    assert_eq!(reorder("\u{1780}\u{17C1}", Script::Khmer), None);
+    assert_eq!(reorder("\u{11107}\u{11127}", Script::Chakma), None);
+}
+
+// https://github.com/n8willis/opentype-shaping-documents/blob/master/opentype-shaping-myanmar.md
+#[test]
+fn myanmar() {
+    let good = good_syllables();
+    let cases = [
+        // kinzi goes after the base, a pre-base matra before it
+        (
+            "\u{1004}\u{103A}\u{1039}\u{1000}\u{1031}",
+            "\u{1031}\u{1000}\u{1004}\u{103A}\u{1039}",
+        ),
+        // the pre-base matra goes before a medial ra, which goes before the base
+        ("\u{1000}\u{103C}\u{1031}", "\u{1031}\u{103C}\u{1000}"),
+        (
+            "\u{1000}\u{103B}\u{103C}\u{1031}",
+            "\u{1031}\u{103C}\u{1000}\u{103B}",
+        ),
+        ("\u{1000}\u{102F}\u{1036}", "\u{1000}\u{1036}\u{102F}"),
+        ("\u{1004}\u{103A}\u{1037}", "\u{1004}\u{1037}\u{103A}"),
+        (
+            "\u{1000}\u{1031}\u{102C}\u{1037}\u{103A}",
+            "\u{1031}\u{1000}\u{102C}\u{1037}\u{103A}",
+        ),
+    ];
+    for &(syllable, expected) in &cases {
+        assert!(good.iter().any(|s| s == syllable), "{:?}", syllable);
+        assert_eq!(
+            visual(syllable, Script::Myanmar),
+            expected,
+            "{:?}",
+            syllable
+        );
+    }
+    // a Shan consonant takes the Burmese pre-base matra like any other base
+    assert_eq!(
+        visual("\u{1075}\u{1031}", Script::Myanmar),
+        "\u{1031}\u{1075}"
+    );
+}
+
+/// Reordering only moves the characters of a syllable, and finds a single base.
+#[test]
+fn myanmar_data_is_permuted() {
+    for syllable in good_syllables() {
+        let reordered = reorder(&syllable, Script::Myanmar).unwrap();
+        let bases = reordered.iter().filter(|&&(_, p)| p == Position::Base);
+        assert_eq!(bases.count(), 1, "{:?} has no single base", syllable);
+        let mut before: Vec<char> = syllable.chars().collect();
+        let mut after: Vec<char> = reordered.iter().map(|&(ch, _)| ch).collect();
+        before.sort_unstable();
+        after.sort_unstable();
+        assert_eq!(before, after, "{:?} reordered as {:?}", syllable, reordered);
+    }
+}
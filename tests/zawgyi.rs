@@ -0,0 +1,86 @@
+//! Tests of telling Zawgyi from Unicode Myanmar text, and of converting it.
+
+extern crate corpus;
+
+use corpus::{zawgyi_score, zawgyi_to_unicode};
+
+/// Words in Zawgyi, and the same words in Unicode
+const WORDS: &[(&str, &str)] = &[
+    // medial ra is typed before the consonant
+    (
+        "\u{103B}\u{1019}\u{1014}\u{1039}\u{1019}\u{102C}",
+        "\u{1019}\u{103C}\u{1014}\u{103A}\u{1019}\u{102C}",
+    ),
+    // so is vowel sign E, and U+103A is medial ya
+    (
+        "\u{1031}\u{1000}\u{103A}\u{102C}\u{1004}\u{1039}\u{1038}",
+        "\u{1000}\u{103B}\u{1031}\u{102C}\u{1004}\u{103A}\u{1038}",
+    ),
+    // kinzi is typed after the consonant
+    (
+        "\u{101E}\u{1001}\u{1064}\u{103A}\u{102C}",
+        "\u{101E}\u{1004}\u{103A}\u{1039}\u{1001}\u{103B}\u{102C}",
+    ),
+    // vowel sign E before an alternate medial ra
+    (
+        "\u{1031}\u{107E}\u{1000}\u{102C}\u{1004}\u{1039}\u{1038}",
+        "\u{1000}\u{103C}\u{1031}\u{102C}\u{1004}\u{103A}\u{1038}",
+    ),
+    // alternate na, and a stacked consonant with a code point of its own
+    (
+        "\u{1019}\u{108F}\u{1071}\u{1031}\u{101C}\u{1038}",
+        "\u{1019}\u{1014}\u{1039}\u{1010}\u{101C}\u{1031}\u{1038}",
+    ),
+    (
+        "\u{1000}\u{1030}\u{1038}\u{101B}\u{1033}",
+        "\u{1000}\u{1030}\u{1038}\u{101B}\u{102F}",
+    ),
+];
+
+#[test]
+fn zawgyi_is_converted() {
+    for &(zawgyi, unicode) in WORDS {
+        assert_eq!(zawgyi_to_unicode(zawgyi), unicode, "{:?}", zawgyi);
+    }
+}
+
+#[test]
+fn zawgyi_is_detected() {
+    for &(zawgyi, unicode) in WORDS {
+        assert!(zawgyi_score(zawgyi).is_zawgyi(), "{:?}", zawgyi);
+        assert!(!zawgyi_score(unicode).is_zawgyi(), "{:?}", unicode);
+    }
+}
+
+#[test]
+fn vowel_sign_e() {
+    // each vowel sign E goes after the consonant typed after it
+    assert_eq!(
+        zawgyi_to_unicode("\u{1031}\u{1000}\u{1031}\u{1001}"),
+        "\u{1000}\u{1031}\u{1001}\u{1031}"
+    );
+    // and after the medial ra typed between them
+    assert_eq!(
+        zawgyi_to_unicode("\u{1031}\u{103B}\u{1000}\u{102C}"),
+        "\u{1000}\u{103C}\u{1031}\u{102C}"
+    );
+    // without a consonant it stays where it is
+    assert_eq!(zawgyi_to_unicode("\u{1031}"), "\u{1031}");
+}
+
+#[test]
+fn mixed_text() {
+    let zawgyi = "Yangon \u{101B}\u{1014}\u{1039}\u{1000}\u{102F}\u{1014}\u{1039} 2024";
+    let unicode = "Yangon \u{101B}\u{1014}\u{103A}\u{1000}\u{102F}\u{1014}\u{103A} 2024";
+    assert_eq!(zawgyi_to_unicode(zawgyi), unicode);
+    assert!(zawgyi_score(zawgyi).is_zawgyi());
+    assert!(!zawgyi_score(unicode).is_zawgyi());
+}
+
+#[test]
+fn other_text_is_left_alone() {
+    for &text in &["", "Myanmar \u{104F} 2024"] {
+        assert_eq!(zawgyi_to_unicode(text), text);
+        assert_eq!(zawgyi_score(text).score(), 0);
+    }
+}
@@ -0,0 +1,1201 @@
+//! The Universal Shaping Engine cluster grammar.
+//!
+//! https://learn.microsoft.com/en-us/typography/script-development/use
+
+use super::diagnose::Role;
+use super::myanmar::{
+    match_either, match_one, match_optional, match_optional_seq, match_repeat_upto, match_seq,
+};
+use super::Syllable;
+
+// "A practical maximum cluster length is 31 characters."
+const MAX_CLUSTER_LEN: usize = 31;
+
+// See myanmar.rs
+const MAX_REPEAT: usize = MAX_CLUSTER_LEN / 3;
+
+/// USE categories, with the abbreviations used by the cluster grammar.
+#[allow(unused)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum UseCategory {
+    Base,                  // B
+    BaseNum,               // N
+    BaseOther,             // GB
+    Cgj,                   // CGJ, also ZWJ and variation selectors
+    ConsSub,               // SUB
+    Halant,                // H
+    HalantNum,             // HN
+    Zwnj,                  // ZWNJ
+    WordJoiner,            // WJ
+    Repha,                 // R
+    Symbol,                // S
+    ConsWithStacker,       // CS
+    InvisibleStacker,      // IS
+    Sakot,                 // Sk
+    HalantOrVowelModifier, // HVM
+    ConsFinalAbove,        // FAbv
+    ConsFinalBelow,        // FBlw
+    ConsFinalPost,         // FPst
+    ConsMedAbove,          // MAbv
+    ConsMedBelow,          // MBlw
+    ConsMedPost,           // MPst
+    ConsMedPre,            // MPre
+    ConsModAbove,          // CMAbv
+    ConsModBelow,          // CMBlw
+    VowelAbove,            // VAbv
+    VowelBelow,            // VBlw
+    VowelPost,             // VPst
+    VowelPre,              // VPre
+    VowelModAbove,         // VMAbv
+    VowelModBelow,         // VMBlw
+    VowelModPost,          // VMPost
+    VowelModPre,           // VMPre
+    SymModAbove,           // SMAbv
+    SymModBelow,           // SMBlw
+    ConsFinalModAbove,     // FMAbv
+    ConsFinalModBelow,     // FMBlw
+    ConsFinalModPost,      // FMPst
+}
+
+fn is(category: UseCategory) -> impl Fn(char) -> bool {
+    move |ch| use_character(ch) == Some(category)
+}
+
+pub(crate) fn repha(ch: char) -> bool {
+    use_character(ch) == Some(UseCategory::Repha)
+}
+
+// _h_ = _H_ | _HVM_ | _IS_ | _Sk_
+fn h(ch: char) -> bool {
+    matches!(
+        use_character(ch),
+        Some(
+            UseCategory::Halant
+                | UseCategory::HalantOrVowelModifier
+                | UseCategory::InvisibleStacker
+                | UseCategory::Sakot
+        )
+    )
+}
+
+// _O_, restricted to the blocks of the scripts in the character table so that spaces and
+// Latin letters don't turn into symbol clusters
+fn other(ch: char) -> bool {
+    use_block(ch) && use_character(ch).is_none()
+}
+
+fn use_block(ch: char) -> bool {
+    matches!(ch,
+        '\u{1A20}'..='\u{1AAF}' // Tai Tham
+        | '\u{1B00}'..='\u{1B7F}' // Balinese
+        | '\u{A980}'..='\u{A9DF}' // Javanese
+        | '\u{11100}'..='\u{1114F}' // Chakma
+        | '\u{11180}'..='\u{111DF}' // Sharada
+        | '\u{11480}'..='\u{114DF}' // Tirhuta
+        | '\u{11F00}'..='\u{11F5F}' // Kawi
+    )
+}
+
+fn ignorable(ch: char) -> bool {
+    use_character(ch) == Some(UseCategory::Cgj)
+}
+
+fn mark(ch: char) -> bool {
+    match use_character(ch) {
+        Some(category) => !matches!(
+            category,
+            UseCategory::Base
+                | UseCategory::BaseNum
+                | UseCategory::BaseOther
+                | UseCategory::Cgj
+                | UseCategory::Zwnj
+                | UseCategory::WordJoiner
+                | UseCategory::Repha
+                | UseCategory::Symbol
+                | UseCategory::ConsWithStacker
+        ),
+        None => false,
+    }
+}
+
+fn match_unit(_cs: &[char]) -> Option<usize> {
+    Some(0)
+}
+
+/// `f* g`
+fn match_star(
+    f: impl Fn(&[char]) -> Option<usize>,
+    g: impl Fn(&[char]) -> Option<usize>,
+) -> impl Fn(&[char]) -> Option<usize> {
+    match_repeat_upto(MAX_REPEAT, f, g)
+}
+
+/// `f+ g`
+fn match_plus(
+    f: impl Fn(&[char]) -> Option<usize> + Copy,
+    g: impl Fn(&[char]) -> Option<usize>,
+) -> impl Fn(&[char]) -> Option<usize> {
+    match_seq(f, match_repeat_upto(MAX_REPEAT, f, g))
+}
+
+// consonant_modifiers = _CMAbv_* _CMBlw_* ((_h_ _B_ | _SUB_) _CMAbv_* _CMBlw_*)*
+fn match_consonant_modifiers(
+    g: impl Fn(&[char]) -> Option<usize>,
+) -> impl Fn(&[char]) -> Option<usize> {
+    match_star(
+        match_one(is(UseCategory::ConsModAbove)),
+        match_star(
+            match_one(is(UseCategory::ConsModBelow)),
+            match_star(
+                match_seq(
+                    match_either(
+                        match_seq(match_one(h), match_one(is(UseCategory::Base))),
+                        match_one(is(UseCategory::ConsSub)),
+                    ),
+                    match_star(
+                        match_one(is(UseCategory::ConsModAbove)),
+                        match_star(match_one(is(UseCategory::ConsModBelow)), match_unit),
+                    ),
+                ),
+                g,
+            ),
+        ),
+    )
+}
+
+// medial_consonants = _MPre_? _MAbv_? _MBlw_? _MPst_?
+fn match_medial_consonants(
+    g: impl Fn(&[char]) -> Option<usize>,
+) -> impl Fn(&[char]) -> Option<usize> {
+    match_optional_seq(
+        match_one(is(UseCategory::ConsMedPre)),
+        match_optional_seq(
+            match_one(is(UseCategory::ConsMedAbove)),
+            match_optional_seq(
+                match_one(is(UseCategory::ConsMedBelow)),
+                match_optional_seq(match_one(is(UseCategory::ConsMedPost)), g),
+            ),
+        ),
+    )
+}
+
+// dependent_vowels = _VPre_* _VAbv_* _VBlw_* _VPst_* | _H_
+fn match_dependent_vowels(
+    g: impl Fn(&[char]) -> Option<usize>,
+) -> impl Fn(&[char]) -> Option<usize> {
+    move |cs: &[char]| {
+        match_either(
+            match_star(
+                match_one(is(UseCategory::VowelPre)),
+                match_star(
+                    match_one(is(UseCategory::VowelAbove)),
+                    match_star(
+                        match_one(is(UseCategory::VowelBelow)),
+                        match_star(match_one(is(UseCategory::VowelPost)), &g),
+                    ),
+                ),
+            ),
+            match_seq(match_one(is(UseCategory::Halant)), &g),
+        )(cs)
+    }
+}
+
+// vowel_modifiers = _HVM_? _VMPre_* _VMAbv_* _VMBlw_* _VMPst_*
+fn match_vowel_modifiers(
+    g: impl Fn(&[char]) -> Option<usize>,
+) -> impl Fn(&[char]) -> Option<usize> {
+    match_optional_seq(
+        match_one(is(UseCategory::HalantOrVowelModifier)),
+        match_star(
+            match_one(is(UseCategory::VowelModPre)),
+            match_star(
+                match_one(is(UseCategory::VowelModAbove)),
+                match_star(
+                    match_one(is(UseCategory::VowelModBelow)),
+                    match_star(match_one(is(UseCategory::VowelModPost)), g),
+                ),
+            ),
+        ),
+    )
+}
+
+// final_consonants = _FAbv_* _FBlw_* _FPst_*
+fn match_final_consonants(
+    g: impl Fn(&[char]) -> Option<usize>,
+) -> impl Fn(&[char]) -> Option<usize> {
+    match_star(
+        match_one(is(UseCategory::ConsFinalAbove)),
+        match_star(
+            match_one(is(UseCategory::ConsFinalBelow)),
+            match_star(match_one(is(UseCategory::ConsFinalPost)), g),
+        ),
+    )
+}
+
+// final_modifiers = _FMAbv_* _FMBlw_* | _FMPst_?
+fn match_final_modifiers(cs: &[char]) -> Option<usize> {
+    match_either(
+        match_star(
+            match_one(is(UseCategory::ConsFinalModAbove)),
+            match_star(match_one(is(UseCategory::ConsFinalModBelow)), match_unit),
+        ),
+        match_optional(match_one(is(UseCategory::ConsFinalModPost))),
+    )(cs)
+}
+
+// complex_syllable_start = (_R_ | _CS_)? (_B_ | _GB_)
+fn match_complex_syllable_start(cs: &[char]) -> Option<usize> {
+    match_optional_seq(
+        match_either(
+            match_one(is(UseCategory::Repha)),
+            match_one(is(UseCategory::ConsWithStacker)),
+        ),
+        match_either(
+            match_one(is(UseCategory::Base)),
+            match_one(is(UseCategory::BaseOther)),
+        ),
+    )(cs)
+}
+
+// complex_syllable_middle =
+//     consonant_modifiers medial_consonants dependent_vowels vowel_modifiers (_Sk_ _B_)*
+fn match_complex_syllable_middle(
+    g: impl Fn(&[char]) -> Option<usize>,
+) -> impl Fn(&[char]) -> Option<usize> {
+    match_consonant_modifiers(match_medial_consonants(match_dependent_vowels(
+        match_vowel_modifiers(match_star(
+            match_seq(
+                match_one(is(UseCategory::Sakot)),
+                match_one(is(UseCategory::Base)),
+            ),
+            g,
+        )),
+    )))
+}
+
+// complex_syllable_tail = complex_syllable_middle final_consonants final_modifiers
+fn match_complex_syllable_tail(cs: &[char]) -> Option<usize> {
+    match_complex_syllable_middle(match_final_consonants(match_final_modifiers))(cs)
+}
+
+// number_joiner_terminated_cluster_tail = (_HN_ _N_)* _HN_
+fn match_number_joiner_terminated_cluster_tail(cs: &[char]) -> Option<usize> {
+    match_star(
+        match_seq(
+            match_one(is(UseCategory::HalantNum)),
+            match_one(is(UseCategory::BaseNum)),
+        ),
+        match_one(is(UseCategory::HalantNum)),
+    )(cs)
+}
+
+// numeral_cluster_tail = (_HN_ _N_)+
+fn match_numeral_cluster_tail(cs: &[char]) -> Option<usize> {
+    match_plus(
+        |cs: &[char]| {
+            match_seq(
+                match_one(is(UseCategory::HalantNum)),
+                match_one(is(UseCategory::BaseNum)),
+            )(cs)
+        },
+        match_unit,
+    )(cs)
+}
+
+// symbol_cluster_tail = _SMAbv_+ _SMBlw_* | _SMBlw_+
+fn match_symbol_cluster_tail(cs: &[char]) -> Option<usize> {
+    match_either(
+        match_plus(
+            |cs: &[char]| match_one(is(UseCategory::SymModAbove))(cs),
+            match_star(match_one(is(UseCategory::SymModBelow)), match_unit),
+        ),
+        match_plus(
+            |cs: &[char]| match_one(is(UseCategory::SymModBelow))(cs),
+            match_unit,
+        ),
+    )(cs)
+}
+
+// virama_terminated_cluster_tail = consonant_modifiers _IS_
+fn match_virama_terminated_cluster_tail(cs: &[char]) -> Option<usize> {
+    match_consonant_modifiers(match_one(is(UseCategory::InvisibleStacker)))(cs)
+}
+
+// sakot_terminated_cluster_tail = complex_syllable_middle _Sk_
+fn match_sakot_terminated_cluster_tail(cs: &[char]) -> Option<usize> {
+    match_complex_syllable_middle(match_one(is(UseCategory::Sakot)))(cs)
+}
+
+// tail = complex_syllable_tail | sakot_terminated_cluster_tail | symbol_cluster_tail
+//      | virama_terminated_cluster_tail
+fn match_tail(cs: &[char]) -> Option<usize> {
+    match_either(
+        match_either(
+            match_complex_syllable_tail,
+            match_sakot_terminated_cluster_tail,
+        ),
+        match_either(
+            match_symbol_cluster_tail,
+            match_virama_terminated_cluster_tail,
+        ),
+    )(cs)
+}
+
+// virama_terminated_cluster = complex_syllable_start virama_terminated_cluster_tail
+fn match_virama_terminated_cluster(cs: &[char]) -> Option<usize> {
+    match_seq(
+        match_complex_syllable_start,
+        match_virama_terminated_cluster_tail,
+    )(cs)
+}
+
+// sakot_terminated_cluster = complex_syllable_start sakot_terminated_cluster_tail
+fn match_sakot_terminated_cluster(cs: &[char]) -> Option<usize> {
+    match_seq(
+        match_complex_syllable_start,
+        match_sakot_terminated_cluster_tail,
+    )(cs)
+}
+
+// standard_cluster = complex_syllable_start complex_syllable_tail
+fn match_standard_cluster(cs: &[char]) -> Option<usize> {
+    match_seq(match_complex_syllable_start, match_complex_syllable_tail)(cs)
+}
+
+// number_joiner_terminated_cluster = _N_ number_joiner_terminated_cluster_tail
+fn match_number_joiner_terminated_cluster(cs: &[char]) -> Option<usize> {
+    match_seq(
+        match_one(is(UseCategory::BaseNum)),
+        match_number_joiner_terminated_cluster_tail,
+    )(cs)
+}
+
+// numeral_cluster = _N_ numeral_cluster_tail?
+fn match_numeral_cluster(cs: &[char]) -> Option<usize> {
+    match_seq(
+        match_one(is(UseCategory::BaseNum)),
+        match_optional(match_numeral_cluster_tail),
+    )(cs)
+}
+
+// symbol_cluster = (_O_ | _GB_) tail?
+fn match_symbol_cluster(cs: &[char]) -> Option<usize> {
+    match_seq(
+        match_either(match_one(other), match_one(is(UseCategory::BaseOther))),
+        match_optional(match_tail),
+    )(cs)
+}
+
+// broken_cluster = _R_? (tail | number_joiner_terminated_cluster_tail | numeral_cluster_tail)
+fn match_broken_cluster(cs: &[char]) -> Option<usize> {
+    match match_optional_seq(
+        match_one(is(UseCategory::Repha)),
+        match_either(
+            match_tail,
+            match_either(
+                match_number_joiner_terminated_cluster_tail,
+                match_numeral_cluster_tail,
+            ),
+        ),
+    )(cs)
+    {
+        Some(len) if len > 0 => Some(len),
+        _ => None,
+    }
+}
+
+/// Matches a cluster in `cs`, which must not contain any ignorable characters.
+fn match_cluster(cs: &[char]) -> Option<(usize, Syllable)> {
+    // As in the Ragel machine, the longest match wins and ties go to the earlier rule
+    let clusters = [
+        (match_virama_terminated_cluster(cs), Syllable::Consonant),
+        (match_sakot_terminated_cluster(cs), Syllable::Consonant),
+        (match_standard_cluster(cs), Syllable::Consonant),
+        (match_number_joiner_terminated_cluster(cs), Syllable::Symbol),
+        (match_numeral_cluster(cs), Syllable::Symbol),
+        (match_symbol_cluster(cs), Syllable::Symbol),
+        (match_broken_cluster(cs), Syllable::Broken),
+    ];
+    let mut best = None;
+    for &(len, syllable) in clusters.iter() {
+        if let Some(len) = len {
+            match best {
+                Some((best_len, _)) if best_len >= len => {}
+                _ => best = Some((len, syllable)),
+            }
+        }
+    }
+    // Every cluster may end with _ZWNJ_
+    best.map(|(len, syllable)| {
+        let zwnj = match_optional(match_one(is(UseCategory::Zwnj)))(&cs[len..]).unwrap_or(0);
+        (len + zwnj, syllable)
+    })
+}
+
+pub fn match_syllable(cs: &str) -> Option<(usize, Syllable)> {
+    // The grammar is matched with CGJ, ZWJ and variation selectors skipped, and with ZWNJ
+    // skipped when it comes before a mark. Keep the original offset of every character
+    // that is matched so the cluster length can be mapped back.
+    let mut chars = Vec::new();
+    let mut offsets = Vec::new();
+    let mut limit = cs.len();
+    for (i, ch) in cs.char_indices() {
+        if offsets.len() > MAX_CLUSTER_LEN {
+            limit = i;
+            break;
+        }
+        if ignorable(ch) {
+            continue;
+        }
+        if is(UseCategory::Zwnj)(ch) {
+            let next = cs[i + ch.len_utf8()..].chars().find(|&ch| !ignorable(ch));
+            if next.is_some_and(mark) {
+                continue;
+            }
+        }
+        offsets.push(i);
+        chars.push(ch);
+    }
+    let (len, syllable) = match_cluster(&chars)?;
+    // Ignorable characters up to the start of the next cluster are part of this one
+    let end = offsets.get(len).map_or(limit, |&i| i);
+    Some((end, syllable))
+}
+
+/// The USE category of `ch` for `super::pattern`.
+pub(crate) fn class_name(ch: char) -> String {
+    super::format_class(ch, use_character(ch), None::<UseCategory>)
+}
+
+/// What `ch` does in a syllable, for `super::diagnose`.
+///
+/// USE has no category for independent vowels, which are bases.
+pub(crate) fn role(ch: char) -> Option<Role> {
+    use self::UseCategory::*;
+
+    Some(match use_character(ch)? {
+        Base | BaseOther | ConsWithStacker => Role::Base,
+        Halant | HalantNum | HalantOrVowelModifier | InvisibleStacker | Sakot => Role::Virama,
+        VowelAbove | VowelBelow | VowelPost | VowelPre => Role::VowelDependent,
+        Cgj | Zwnj | WordJoiner => Role::Joiner,
+        BaseNum | Symbol => Role::Other,
+        _ => Role::Mark,
+    })
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// USE character tables
+/////////////////////////////////////////////////////////////////////////////
+
+fn use_character(ch: char) -> Option<UseCategory> {
+    use self::UseCategory::*;
+
+    match ch as u32 {
+        // Balinese character table
+        0x1B00 => Some(VowelModAbove),  // ᬀ Sign Ulu Ricem
+        0x1B01 => Some(VowelModAbove),  // ᬁ Sign Ulu Candra
+        0x1B02 => Some(VowelModAbove),  // ᬂ Sign Cecek
+        0x1B03 => Some(ConsFinalAbove), // ᬃ Sign Surang
+        0x1B04 => Some(VowelModPost),   // ᬄ Sign Bisah
+        0x1B05 => Some(Base),           // ᬅ Akara
+        0x1B06 => Some(Base),           // ᬆ Akara Tedung
+        0x1B07 => Some(Base),           // ᬇ Ikara
+        0x1B08 => Some(Base),           // ᬈ Ikara Tedung
+        0x1B09 => Some(Base),           // ᬉ Ukara
+        0x1B0A => Some(Base),           // ᬊ Ukara Tedung
+        0x1B0B => Some(Base),           // ᬋ Ra Repa
+        0x1B0C => Some(Base),           // ᬌ Ra Repa Tedung
+        0x1B0D => Some(Base),           // ᬍ La Lenga
+        0x1B0E => Some(Base),           // ᬎ La Lenga Tedung
+        0x1B0F => Some(Base),           // ᬏ Ekara
+        0x1B10 => Some(Base),           // ᬐ Aikara
+        0x1B11 => Some(Base),           // ᬑ Okara
+        0x1B12 => Some(Base),           // ᬒ Okara Tedung
+        0x1B13 => Some(Base),           // ᬓ Ka
+        0x1B14 => Some(Base),           // ᬔ Ka Mahaprana
+        0x1B15 => Some(Base),           // ᬕ Ga
+        0x1B16 => Some(Base),           // ᬖ Ga Gora
+        0x1B17 => Some(Base),           // ᬗ Nga
+        0x1B18 => Some(Base),           // ᬘ Ca
+        0x1B19 => Some(Base),           // ᬙ Ca Laca
+        0x1B1A => Some(Base),           // ᬚ Ja
+        0x1B1B => Some(Base),           // ᬛ Ja Jera
+        0x1B1C => Some(Base),           // ᬜ Nya
+        0x1B1D => Some(Base),           // ᬝ Ta Latik
+        0x1B1E => Some(Base),           // ᬞ Ta Murda Mahaprana
+        0x1B1F => Some(Base),           // ᬟ Da Murda Alpaprana
+        0x1B20 => Some(Base),           // ᬠ Da Murda Mahaprana
+        0x1B21 => Some(Base),           // ᬡ Na Rambat
+        0x1B22 => Some(Base),           // ᬢ Ta
+        0x1B23 => Some(Base),           // ᬣ Ta Tawa
+        0x1B24 => Some(Base),           // ᬤ Da
+        0x1B25 => Some(Base),           // ᬥ Da Madu
+        0x1B26 => Some(Base),           // ᬦ Na
+        0x1B27 => Some(Base),           // ᬧ Pa
+        0x1B28 => Some(Base),           // ᬨ Pa Kapal
+        0x1B29 => Some(Base),           // ᬩ Ba
+        0x1B2A => Some(Base),           // ᬪ Ba Kembang
+        0x1B2B => Some(Base),           // ᬫ Ma
+        0x1B2C => Some(Base),           // ᬬ Ya
+        0x1B2D => Some(Base),           // ᬭ Ra
+        0x1B2E => Some(Base),           // ᬮ La
+        0x1B2F => Some(Base),           // ᬯ Wa
+        0x1B30 => Some(Base),           // ᬰ Sa Saga
+        0x1B31 => Some(Base),           // ᬱ Sa Sapa
+        0x1B32 => Some(Base),           // ᬲ Sa
+        0x1B33 => Some(Base),           // ᬳ Ha
+        0x1B34 => Some(ConsModAbove),   // ᬴ Sign Rerekan
+        0x1B35 => Some(VowelPost),      // ᬵ Sign Tedung
+        0x1B36 => Some(VowelAbove),     // ᬶ Sign Ulu
+        0x1B37 => Some(VowelAbove),     // ᬷ Sign Ulu Sari
+        0x1B38 => Some(VowelBelow),     // ᬸ Sign Suku
+        0x1B39 => Some(VowelBelow),     // ᬹ Sign Suku Ilut
+        0x1B3A => Some(VowelBelow),     // ᬺ Sign Ra Repa
+        0x1B3B => Some(VowelBelow),     // ᬻ Sign Ra Repa Tedung
+        0x1B3C => Some(VowelAbove),     // ᬼ Sign La Lenga
+        0x1B3D => Some(VowelAbove),     // ᬽ Sign La Lenga Tedung
+        0x1B3E => Some(VowelPre),       // ᬾ Sign Taling
+        0x1B3F => Some(VowelPre),       // ᬿ Sign Taling Repa
+        0x1B40 => Some(VowelPre),       // ᭀ Sign Taling Tedung
+        0x1B41 => Some(VowelPre),       // ᭁ Sign Taling Repa Tedung
+        0x1B42 => Some(VowelAbove),     // ᭂ Sign Pepet
+        0x1B43 => Some(VowelAbove),     // ᭃ Sign Pepet Tedung
+        0x1B44 => Some(Halant),         // ᭄ Adeg Adeg
+        0x1B45 => Some(Base),           // ᭅ Kaf Sasak
+        0x1B46 => Some(Base),           // ᭆ Khot Sasak
+        0x1B47 => Some(Base),           // ᭇ Tzir Sasak
+        0x1B48 => Some(Base),           // ᭈ Ef Sasak
+        0x1B49 => Some(Base),           // ᭉ Ve Sasak
+        0x1B4A => Some(Base),           // ᭊ Zal Sasak
+        0x1B4B => Some(Base),           // ᭋ Asyura Sasak
+        0x1B4C => Some(Base),           // ᭌ Archaic Jnya
+        0x1B50 => Some(Base),           // ᭐ Digit Zero
+        0x1B51 => Some(Base),           // ᭑ Digit One
+        0x1B52 => Some(Base),           // ᭒ Digit Two
+        0x1B53 => Some(Base),           // ᭓ Digit Three
+        0x1B54 => Some(Base),           // ᭔ Digit Four
+        0x1B55 => Some(Base),           // ᭕ Digit Five
+        0x1B56 => Some(Base),           // ᭖ Digit Six
+        0x1B57 => Some(Base),           // ᭗ Digit Seven
+        0x1B58 => Some(Base),           // ᭘ Digit Eight
+        0x1B59 => Some(Base),           // ᭙ Digit Nine
+        0x1B5A => None,                 // ᭚ Panti
+        0x1B5B => None,                 // ᭛ Pamada
+        0x1B5C => None,                 // ᭜ Windu
+        0x1B5D => None,                 // ᭝ Carik Pamungkah
+        0x1B5E => None,                 // ᭞ Carik Siki
+        0x1B5F => None,                 // ᭟ Carik Pareren
+        0x1B60 => None,                 // ᭠ Pameneng
+        0x1B61 => None,                 // ᭡ Musical Symbol Dong
+        0x1B62 => None,                 // ᭢ Musical Symbol Deng
+        0x1B63 => None,                 // ᭣ Musical Symbol Dung
+        0x1B64 => None,                 // ᭤ Musical Symbol Dang
+        0x1B65 => None,                 // ᭥ Musical Symbol Dang Surang
+        0x1B66 => None,                 // ᭦ Musical Symbol Ding
+        0x1B67 => None,                 // ᭧ Musical Symbol Daeng
+        0x1B68 => None,                 // ᭨ Musical Symbol Deung
+        0x1B69 => None,                 // ᭩ Musical Symbol Daing
+        0x1B6A => None,                 // ᭪ Musical Symbol Dang Gede
+        0x1B6B => Some(SymModAbove),    // ᭫ Musical Symbol Combining Tegeh
+        0x1B6C => Some(SymModAbove),    // ᭬ Musical Symbol Combining Endep
+        0x1B6D => Some(SymModAbove),    // ᭭ Musical Symbol Combining Kempul
+        0x1B6E => Some(SymModAbove),    // ᭮ Musical Symbol Combining Kempli
+        0x1B6F => Some(SymModAbove),    // ᭯ Musical Symbol Combining Jegogan
+        0x1B70 => Some(SymModAbove),    // ᭰ Musical Symbol Combining Kempul With Jegogan
+        0x1B71 => Some(SymModAbove),    // ᭱ Musical Symbol Combining Kempli With Jegogan
+        0x1B72 => Some(SymModAbove),    // ᭲ Musical Symbol Combining Bende
+        0x1B73 => Some(SymModAbove),    // ᭳ Musical Symbol Combining Gong
+        0x1B74 => None,                 // ᭴ Musical Symbol Right-hand Open Dug
+        0x1B75 => None,                 // ᭵ Musical Symbol Right-hand Open Dag
+        0x1B76 => None,                 // ᭶ Musical Symbol Right-hand Closed Tuk
+        0x1B77 => None,                 // ᭷ Musical Symbol Right-hand Closed Tak
+        0x1B78 => None,                 // ᭸ Musical Symbol Left-hand Open Pang
+        0x1B79 => None,                 // ᭹ Musical Symbol Left-hand Open Pung
+        0x1B7A => None,                 // ᭺ Musical Symbol Left-hand Closed Plak
+        0x1B7B => None,                 // ᭻ Musical Symbol Left-hand Closed Pluk
+        0x1B7C => None,                 // ᭼ Musical Symbol Left-hand Open Ping
+        0x1B7D => None,                 // ᭽ Panti Lantang
+        0x1B7E => None,                 // ᭾ Pamada Lantang
+
+        // Javanese character table
+        0xA980 => Some(VowelModAbove),  // ꦀ Sign Panyangga
+        0xA981 => Some(VowelModAbove),  // ꦁ Sign Cecak
+        0xA982 => Some(ConsFinalAbove), // ꦂ Sign Layar
+        0xA983 => Some(VowelModPost),   // ꦃ Sign Wignyan
+        0xA984 => Some(Base),           // ꦄ A
+        0xA985 => Some(Base),           // ꦅ I Kawi
+        0xA986 => Some(Base),           // ꦆ I
+        0xA987 => Some(Base),           // ꦇ Ii
+        0xA988 => Some(Base),           // ꦈ U
+        0xA989 => Some(Base),           // ꦉ Pa Cerek
+        0xA98A => Some(Base),           // ꦊ Nga Lelet
+        0xA98B => Some(Base),           // ꦋ Nga Lelet Raswadi
+        0xA98C => Some(Base),           // ꦌ E
+        0xA98D => Some(Base),           // ꦍ Ai
+        0xA98E => Some(Base),           // ꦎ O
+        0xA98F => Some(Base),           // ꦏ Ka
+        0xA990 => Some(Base),           // ꦐ Ka Sasak
+        0xA991 => Some(Base),           // ꦑ Ka Murda
+        0xA992 => Some(Base),           // ꦒ Ga
+        0xA993 => Some(Base),           // ꦓ Ga Murda
+        0xA994 => Some(Base),           // ꦔ Nga
+        0xA995 => Some(Base),           // ꦕ Ca
+        0xA996 => Some(Base),           // ꦖ Ca Murda
+        0xA997 => Some(Base),           // ꦗ Ja
+        0xA998 => Some(Base),           // ꦘ Nya Murda
+        0xA999 => Some(Base),           // ꦙ Ja Mahaprana
+        0xA99A => Some(Base),           // ꦚ Nya
+        0xA99B => Some(Base),           // ꦛ Tta
+        0xA99C => Some(Base),           // ꦜ Tta Mahaprana
+        0xA99D => Some(Base),           // ꦝ Dda
+        0xA99E => Some(Base),           // ꦞ Dda Mahaprana
+        0xA99F => Some(Base),           // ꦟ Na Murda
+        0xA9A0 => Some(Base),           // ꦠ Ta
+        0xA9A1 => Some(Base),           // ꦡ Ta Murda
+        0xA9A2 => Some(Base),           // ꦢ Da
+        0xA9A3 => Some(Base),           // ꦣ Da Mahaprana
+        0xA9A4 => Some(Base),           // ꦤ Na
+        0xA9A5 => Some(Base),           // ꦥ Pa
+        0xA9A6 => Some(Base),           // ꦦ Pa Murda
+        0xA9A7 => Some(Base),           // ꦧ Ba
+        0xA9A8 => Some(Base),           // ꦨ Ba Murda
+        0xA9A9 => Some(Base),           // ꦩ Ma
+        0xA9AA => Some(Base),           // ꦪ Ya
+        0xA9AB => Some(Base),           // ꦫ Ra
+        0xA9AC => Some(Base),           // ꦬ Ra Agung
+        0xA9AD => Some(Base),           // ꦭ La
+        0xA9AE => Some(Base),           // ꦮ Wa
+        0xA9AF => Some(Base),           // ꦯ Sa Murda
+        0xA9B0 => Some(Base),           // ꦰ Sa Mahaprana
+        0xA9B1 => Some(Base),           // ꦱ Sa
+        0xA9B2 => Some(Base),           // ꦲ Ha
+        0xA9B3 => Some(ConsModAbove),   // ꦳ Sign Cecak Telu
+        0xA9B4 => Some(VowelPost),      // ꦴ Sign Tarung
+        0xA9B5 => Some(VowelPost),      // ꦵ Sign Tolong
+        0xA9B6 => Some(VowelAbove),     // ꦶ Sign Wulu
+        0xA9B7 => Some(VowelAbove),     // ꦷ Sign Wulu Melik
+        0xA9B8 => Some(VowelBelow),     // ꦸ Sign Suku
+        0xA9B9 => Some(VowelBelow),     // ꦹ Sign Suku Mendut
+        0xA9BA => Some(VowelPre),       // ꦺ Sign Taling
+        0xA9BB => Some(VowelPre),       // ꦻ Sign Dirga Mure
+        0xA9BC => Some(VowelAbove),     // ꦼ Sign Pepet
+        0xA9BD => Some(ConsMedBelow),   // ꦽ Sign Keret
+        0xA9BE => Some(ConsMedPost),    // ꦾ Sign Pengkal
+        0xA9BF => Some(ConsMedBelow),   // ꦿ Sign Cakra
+        0xA9C0 => Some(Halant),         // ꧀ Pangkon
+        0xA9C1 => None,                 // ꧁ Left Rerenggan
+        0xA9C2 => None,                 // ꧂ Right Rerenggan
+        0xA9C3 => None,                 // ꧃ Pada Andap
+        0xA9C4 => None,                 // ꧄ Pada Madya
+        0xA9C5 => None,                 // ꧅ Pada Luhur
+        0xA9C6 => None,                 // ꧆ Pada Windu
+        0xA9C7 => None,                 // ꧇ Pada Pangkat
+        0xA9C8 => None,                 // ꧈ Pada Lingsa
+        0xA9C9 => None,                 // ꧉ Pada Lungsi
+        0xA9CA => None,                 // ꧊ Pada Adeg
+        0xA9CB => None,                 // ꧋ Pada Adeg Adeg
+        0xA9CC => None,                 // ꧌ Pada Piseleh
+        0xA9CD => None,                 // ꧍ Turned Pada Piseleh
+        0xA9CF => None,                 // ꧏ Pangrangkep
+        0xA9D0 => Some(Base),           // ꧐ Digit Zero
+        0xA9D1 => Some(Base),           // ꧑ Digit One
+        0xA9D2 => Some(Base),           // ꧒ Digit Two
+        0xA9D3 => Some(Base),           // ꧓ Digit Three
+        0xA9D4 => Some(Base),           // ꧔ Digit Four
+        0xA9D5 => Some(Base),           // ꧕ Digit Five
+        0xA9D6 => Some(Base),           // ꧖ Digit Six
+        0xA9D7 => Some(Base),           // ꧗ Digit Seven
+        0xA9D8 => Some(Base),           // ꧘ Digit Eight
+        0xA9D9 => Some(Base),           // ꧙ Digit Nine
+        0xA9DE => None,                 // ꧞ Pada Tirta Tumetes
+        0xA9DF => None,                 // ꧟ Pada Isen-isen
+
+        // Tai Tham character table
+        0x1A20 => Some(Base),           // ᨠ High Ka
+        0x1A21 => Some(Base),           // ᨡ High Kha
+        0x1A22 => Some(Base),           // ᨢ High Kxa
+        0x1A23 => Some(Base),           // ᨣ Low Ka
+        0x1A24 => Some(Base),           // ᨤ Low Kxa
+        0x1A25 => Some(Base),           // ᨥ Low Kha
+        0x1A26 => Some(Base),           // ᨦ Nga
+        0x1A27 => Some(Base),           // ᨧ High Ca
+        0x1A28 => Some(Base),           // ᨨ High Cha
+        0x1A29 => Some(Base),           // ᨩ Low Ca
+        0x1A2A => Some(Base),           // ᨪ Low Sa
+        0x1A2B => Some(Base),           // ᨫ Low Cha
+        0x1A2C => Some(Base),           // ᨬ Nya
+        0x1A2D => Some(Base),           // ᨭ Rata
+        0x1A2E => Some(Base),           // ᨮ High Ratha
+        0x1A2F => Some(Base),           // ᨯ Da
+        0x1A30 => Some(Base),           // ᨰ Low Ratha
+        0x1A31 => Some(Base),           // ᨱ Rana
+        0x1A32 => Some(Base),           // ᨲ High Ta
+        0x1A33 => Some(Base),           // ᨳ High Tha
+        0x1A34 => Some(Base),           // ᨴ Low Ta
+        0x1A35 => Some(Base),           // ᨵ Low Tha
+        0x1A36 => Some(Base),           // ᨶ Na
+        0x1A37 => Some(Base),           // ᨷ Ba
+        0x1A38 => Some(Base),           // ᨸ High Pa
+        0x1A39 => Some(Base),           // ᨹ High Pha
+        0x1A3A => Some(Base),           // ᨺ High Fa
+        0x1A3B => Some(Base),           // ᨻ Low Pa
+        0x1A3C => Some(Base),           // ᨼ Low Fa
+        0x1A3D => Some(Base),           // ᨽ Low Pha
+        0x1A3E => Some(Base),           // ᨾ Ma
+        0x1A3F => Some(Base),           // ᨿ Low Ya
+        0x1A40 => Some(Base),           // ᩀ High Ya
+        0x1A41 => Some(Base),           // ᩁ Ra
+        0x1A42 => Some(Base),           // ᩂ Rue
+        0x1A43 => Some(Base),           // ᩃ La
+        0x1A44 => Some(Base),           // ᩄ Lue
+        0x1A45 => Some(Base),           // ᩅ Wa
+        0x1A46 => Some(Base),           // ᩆ High Sha
+        0x1A47 => Some(Base),           // ᩇ High Ssa
+        0x1A48 => Some(Base),           // ᩈ High Sa
+        0x1A49 => Some(Base),           // ᩉ High Ha
+        0x1A4A => Some(Base),           // ᩊ Lla
+        0x1A4B => Some(Base),           // ᩋ A
+        0x1A4C => Some(Base),           // ᩌ Low Ha
+        0x1A4D => Some(Base),           // ᩍ I
+        0x1A4E => Some(Base),           // ᩎ Ii
+        0x1A4F => Some(Base),           // ᩏ U
+        0x1A50 => Some(Base),           // ᩐ Uu
+        0x1A51 => Some(Base),           // ᩑ Ee
+        0x1A52 => Some(Base),           // ᩒ Oo
+        0x1A53 => Some(Base),           // ᩓ Lae
+        0x1A54 => Some(Base),           // ᩔ Great Sa
+        0x1A55 => Some(ConsMedPre),     // ᩕ Sign Medial Ra
+        0x1A56 => Some(ConsMedBelow),   // ᩖ Sign Medial La
+        0x1A57 => Some(ConsSub),        // ᩗ Sign La Tang Lai
+        0x1A58 => Some(ConsFinalAbove), // ᩘ Sign Mai Kang Lai
+        0x1A59 => Some(ConsFinalAbove), // ᩙ Sign Final Nga
+        0x1A5A => Some(ConsMedAbove),   // ᩚ Sign Low Pa
+        0x1A5B => Some(ConsSub),        // ᩛ Sign High Ratha Or Low Pa
+        0x1A5C => Some(ConsSub),        // ᩜ Sign Ma
+        0x1A5D => Some(ConsSub),        // ᩝ Sign Ba
+        0x1A5E => Some(ConsSub),        // ᩞ Sign Sa
+        0x1A60 => Some(Sakot),          // ᩠ Sign Sakot
+        0x1A61 => Some(VowelPost),      // ᩡ Sign A
+        0x1A62 => Some(VowelAbove),     // ᩢ Sign Mai Sat
+        0x1A63 => Some(VowelPost),      // ᩣ Sign Aa
+        0x1A64 => Some(VowelPost),      // ᩤ Sign Tall Aa
+        0x1A65 => Some(VowelAbove),     // ᩥ Sign I
+        0x1A66 => Some(VowelAbove),     // ᩦ Sign Ii
+        0x1A67 => Some(VowelAbove),     // ᩧ Sign Ue
+        0x1A68 => Some(VowelAbove),     // ᩨ Sign Uue
+        0x1A69 => Some(VowelBelow),     // ᩩ Sign U
+        0x1A6A => Some(VowelBelow),     // ᩪ Sign Uu
+        0x1A6B => Some(VowelAbove),     // ᩫ Sign O
+        0x1A6C => Some(VowelBelow),     // ᩬ Sign Oa Below
+        0x1A6D => Some(VowelPost),      // ᩭ Sign Oy
+        0x1A6E => Some(VowelPre),       // ᩮ Sign E
+        0x1A6F => Some(VowelPre),       // ᩯ Sign Ae
+        0x1A70 => Some(VowelPre),       // ᩰ Sign Oo
+        0x1A71 => Some(VowelPre),       // ᩱ Sign Ai
+        0x1A72 => Some(VowelPre),       // ᩲ Sign Tham Ai
+        0x1A73 => Some(VowelAbove),     // ᩳ Sign Oa Above
+        0x1A74 => Some(VowelModAbove),  // ᩴ Sign Mai Kang
+        0x1A75 => Some(VowelModAbove),  // ᩵ Sign Tone-1
+        0x1A76 => Some(VowelModAbove),  // ᩶ Sign Tone-2
+        0x1A77 => Some(VowelModAbove),  // ᩷ Sign Khuen Tone-3
+        0x1A78 => Some(VowelModAbove),  // ᩸ Sign Khuen Tone-4
+        0x1A79 => Some(VowelModAbove),  // ᩹ Sign Khuen Tone-5
+        0x1A7A => Some(VowelAbove),     // ᩺ Sign Ra Haam
+        0x1A7B => Some(VowelModAbove),  // ᩻ Sign Mai Sam
+        0x1A7C => Some(VowelModAbove),  // ᩼ Sign Khuen-lue Karan
+        0x1A7F => Some(VowelModBelow),  // ᩿ Combining Cryptogrammic Dot
+        0x1A80 => Some(Base),           // ᪀ Hora Digit Zero
+        0x1A81 => Some(Base),           // ᪁ Hora Digit One
+        0x1A82 => Some(Base),           // ᪂ Hora Digit Two
+        0x1A83 => Some(Base),           // ᪃ Hora Digit Three
+        0x1A84 => Some(Base),           // ᪄ Hora Digit Four
+        0x1A85 => Some(Base),           // ᪅ Hora Digit Five
+        0x1A86 => Some(Base),           // ᪆ Hora Digit Six
+        0x1A87 => Some(Base),           // ᪇ Hora Digit Seven
+        0x1A88 => Some(Base),           // ᪈ Hora Digit Eight
+        0x1A89 => Some(Base),           // ᪉ Hora Digit Nine
+        0x1A90 => Some(Base),           // ᪐ Tham Digit Zero
+        0x1A91 => Some(Base),           // ᪑ Tham Digit One
+        0x1A92 => Some(Base),           // ᪒ Tham Digit Two
+        0x1A93 => Some(Base),           // ᪓ Tham Digit Three
+        0x1A94 => Some(Base),           // ᪔ Tham Digit Four
+        0x1A95 => Some(Base),           // ᪕ Tham Digit Five
+        0x1A96 => Some(Base),           // ᪖ Tham Digit Six
+        0x1A97 => Some(Base),           // ᪗ Tham Digit Seven
+        0x1A98 => Some(Base),           // ᪘ Tham Digit Eight
+        0x1A99 => Some(Base),           // ᪙ Tham Digit Nine
+        0x1AA0 => None,                 // ᪠ Sign Wiang
+        0x1AA1 => None,                 // ᪡ Sign Wiangwaak
+        0x1AA2 => None,                 // ᪢ Sign Sawan
+        0x1AA3 => None,                 // ᪣ Sign Keow
+        0x1AA4 => None,                 // ᪤ Sign Hoy
+        0x1AA5 => None,                 // ᪥ Sign Dokmai
+        0x1AA6 => None,                 // ᪦ Sign Reversed Rotated Rana
+        0x1AA7 => None,                 // ᪧ Sign Mai Yamok
+        0x1AA8 => None,                 // ᪨ Sign Kaan
+        0x1AA9 => None,                 // ᪩ Sign Kaankuu
+        0x1AAA => None,                 // ᪪ Sign Satkaan
+        0x1AAB => None,                 // ᪫ Sign Satkaankuu
+        0x1AAC => None,                 // ᪬ Sign Hang
+        0x1AAD => None,                 // ᪭ Sign Caang
+
+        // Chakma character table
+        0x11100 => Some(VowelModAbove),    // 𑄀 Sign Candrabindu
+        0x11101 => Some(VowelModAbove),    // 𑄁 Sign Anusvara
+        0x11102 => Some(VowelModAbove),    // 𑄂 Sign Visarga
+        0x11103 => Some(Base),             // 𑄃 Aa
+        0x11104 => Some(Base),             // 𑄄 I
+        0x11105 => Some(Base),             // 𑄅 U
+        0x11106 => Some(Base),             // 𑄆 E
+        0x11107 => Some(Base),             // 𑄇 Kaa
+        0x11108 => Some(Base),             // 𑄈 Khaa
+        0x11109 => Some(Base),             // 𑄉 Gaa
+        0x1110A => Some(Base),             // 𑄊 Ghaa
+        0x1110B => Some(Base),             // 𑄋 Ngaa
+        0x1110C => Some(Base),             // 𑄌 Caa
+        0x1110D => Some(Base),             // 𑄍 Chaa
+        0x1110E => Some(Base),             // 𑄎 Jaa
+        0x1110F => Some(Base),             // 𑄏 Jhaa
+        0x11110 => Some(Base),             // 𑄐 Nyaa
+        0x11111 => Some(Base),             // 𑄑 Ttaa
+        0x11112 => Some(Base),             // 𑄒 Tthaa
+        0x11113 => Some(Base),             // 𑄓 Ddaa
+        0x11114 => Some(Base),             // 𑄔 Ddhaa
+        0x11115 => Some(Base),             // 𑄕 Nnaa
+        0x11116 => Some(Base),             // 𑄖 Taa
+        0x11117 => Some(Base),             // 𑄗 Thaa
+        0x11118 => Some(Base),             // 𑄘 Daa
+        0x11119 => Some(Base),             // 𑄙 Dhaa
+        0x1111A => Some(Base),             // 𑄚 Naa
+        0x1111B => Some(Base),             // 𑄛 Paa
+        0x1111C => Some(Base),             // 𑄜 Phaa
+        0x1111D => Some(Base),             // 𑄝 Baa
+        0x1111E => Some(Base),             // 𑄞 Bhaa
+        0x1111F => Some(Base),             // 𑄟 Maa
+        0x11120 => Some(Base),             // 𑄠 Yyaa
+        0x11121 => Some(Base),             // 𑄡 Yaa
+        0x11122 => Some(Base),             // 𑄢 Raa
+        0x11123 => Some(Base),             // 𑄣 Laa
+        0x11124 => Some(Base),             // 𑄤 Waa
+        0x11125 => Some(Base),             // 𑄥 Saa
+        0x11126 => Some(Base),             // 𑄦 Haa
+        0x11127 => Some(VowelBelow),       // 𑄧 Sign A
+        0x11128 => Some(VowelBelow),       // 𑄨 Sign I
+        0x11129 => Some(VowelBelow),       // 𑄩 Sign Ii
+        0x1112A => Some(VowelAbove),       // 𑄪 Sign U
+        0x1112B => Some(VowelAbove),       // 𑄫 Sign Uu
+        0x1112C => Some(VowelPre),         // 𑄬 Sign E
+        0x1112D => Some(VowelBelow),       // 𑄭 Sign Ai
+        0x1112E => Some(VowelAbove),       // 𑄮 Sign O
+        0x1112F => Some(VowelAbove),       // 𑄯 Sign Au
+        0x11130 => Some(VowelBelow),       // 𑄰 Sign Oi
+        0x11131 => Some(VowelAbove),       // 𑄱 O Mark
+        0x11132 => Some(VowelAbove),       // 𑄲 Au Mark
+        0x11133 => Some(InvisibleStacker), // 𑄳 Virama
+        0x11134 => Some(ConsModAbove),     // 𑄴 Maayyaa
+        0x11136 => Some(Base),             // 𑄶 Digit Zero
+        0x11137 => Some(Base),             // 𑄷 Digit One
+        0x11138 => Some(Base),             // 𑄸 Digit Two
+        0x11139 => Some(Base),             // 𑄹 Digit Three
+        0x1113A => Some(Base),             // 𑄺 Digit Four
+        0x1113B => Some(Base),             // 𑄻 Digit Five
+        0x1113C => Some(Base),             // 𑄼 Digit Six
+        0x1113D => Some(Base),             // 𑄽 Digit Seven
+        0x1113E => Some(Base),             // 𑄾 Digit Eight
+        0x1113F => Some(Base),             // 𑄿 Digit Nine
+        0x11140 => None,                   // 𑅀 Section Mark
+        0x11141 => None,                   // 𑅁 Danda
+        0x11142 => None,                   // 𑅂 Double Danda
+        0x11143 => None,                   // 𑅃 Question Mark
+        0x11144 => Some(Base),             // 𑅄 Lhaa
+        0x11145 => Some(VowelPost),        // 𑅅 Sign Aa
+        0x11146 => Some(VowelPost),        // 𑅆 Sign Ei
+        0x11147 => Some(Base),             // 𑅇 Vaa
+
+        // Sharada character table
+        0x11180 => Some(VowelModAbove),     // 𑆀 Sign Candrabindu
+        0x11181 => Some(VowelModAbove),     // 𑆁 Sign Anusvara
+        0x11182 => Some(VowelModPost),      // 𑆂 Sign Visarga
+        0x11183 => Some(Base),              // 𑆃 A
+        0x11184 => Some(Base),              // 𑆄 Aa
+        0x11185 => Some(Base),              // 𑆅 I
+        0x11186 => Some(Base),              // 𑆆 Ii
+        0x11187 => Some(Base),              // 𑆇 U
+        0x11188 => Some(Base),              // 𑆈 Uu
+        0x11189 => Some(Base),              // 𑆉 Vocalic R
+        0x1118A => Some(Base),              // 𑆊 Vocalic Rr
+        0x1118B => Some(Base),              // 𑆋 Vocalic L
+        0x1118C => Some(Base),              // 𑆌 Vocalic Ll
+        0x1118D => Some(Base),              // 𑆍 E
+        0x1118E => Some(Base),              // 𑆎 Ai
+        0x1118F => Some(Base),              // 𑆏 O
+        0x11190 => Some(Base),              // 𑆐 Au
+        0x11191 => Some(Base),              // 𑆑 Ka
+        0x11192 => Some(Base),              // 𑆒 Kha
+        0x11193 => Some(Base),              // 𑆓 Ga
+        0x11194 => Some(Base),              // 𑆔 Gha
+        0x11195 => Some(Base),              // 𑆕 Nga
+        0x11196 => Some(Base),              // 𑆖 Ca
+        0x11197 => Some(Base),              // 𑆗 Cha
+        0x11198 => Some(Base),              // 𑆘 Ja
+        0x11199 => Some(Base),              // 𑆙 Jha
+        0x1119A => Some(Base),              // 𑆚 Nya
+        0x1119B => Some(Base),              // 𑆛 Tta
+        0x1119C => Some(Base),              // 𑆜 Ttha
+        0x1119D => Some(Base),              // 𑆝 Dda
+        0x1119E => Some(Base),              // 𑆞 Ddha
+        0x1119F => Some(Base),              // 𑆟 Nna
+        0x111A0 => Some(Base),              // 𑆠 Ta
+        0x111A1 => Some(Base),              // 𑆡 Tha
+        0x111A2 => Some(Base),              // 𑆢 Da
+        0x111A3 => Some(Base),              // 𑆣 Dha
+        0x111A4 => Some(Base),              // 𑆤 Na
+        0x111A5 => Some(Base),              // 𑆥 Pa
+        0x111A6 => Some(Base),              // 𑆦 Pha
+        0x111A7 => Some(Base),              // 𑆧 Ba
+        0x111A8 => Some(Base),              // 𑆨 Bha
+        0x111A9 => Some(Base),              // 𑆩 Ma
+        0x111AA => Some(Base),              // 𑆪 Ya
+        0x111AB => Some(Base),              // 𑆫 Ra
+        0x111AC => Some(Base),              // 𑆬 La
+        0x111AD => Some(Base),              // 𑆭 Lla
+        0x111AE => Some(Base),              // 𑆮 Va
+        0x111AF => Some(Base),              // 𑆯 Sha
+        0x111B0 => Some(Base),              // 𑆰 Ssa
+        0x111B1 => Some(Base),              // 𑆱 Sa
+        0x111B2 => Some(Base),              // 𑆲 Ha
+        0x111B3 => Some(VowelPost),         // 𑆳 Sign Aa
+        0x111B4 => Some(VowelPre),          // 𑆴 Sign I
+        0x111B5 => Some(VowelPost),         // 𑆵 Sign Ii
+        0x111B6 => Some(VowelBelow),        // 𑆶 Sign U
+        0x111B7 => Some(VowelBelow),        // 𑆷 Sign Uu
+        0x111B8 => Some(VowelBelow),        // 𑆸 Sign Vocalic R
+        0x111B9 => Some(VowelBelow),        // 𑆹 Sign Vocalic Rr
+        0x111BA => Some(VowelBelow),        // 𑆺 Sign Vocalic L
+        0x111BB => Some(VowelBelow),        // 𑆻 Sign Vocalic Ll
+        0x111BC => Some(VowelAbove),        // 𑆼 Sign E
+        0x111BD => Some(VowelAbove),        // 𑆽 Sign Ai
+        0x111BE => Some(VowelAbove),        // 𑆾 Sign O
+        0x111BF => Some(VowelAbove),        // 𑆿 Sign Au
+        0x111C0 => Some(Halant),            // 𑇀 Sign Virama
+        0x111C1 => Some(Base),              // 𑇁 Sign Avagraha
+        0x111C2 => Some(Repha),             // 𑇂 Sign Jihvamuliya
+        0x111C3 => Some(Repha),             // 𑇃 Sign Upadhmaniya
+        0x111C4 => None,                    // 𑇄 Om
+        0x111C5 => None,                    // 𑇅 Danda
+        0x111C6 => None,                    // 𑇆 Double Danda
+        0x111C7 => None,                    // 𑇇 Abbreviation Sign
+        0x111C8 => None,                    // 𑇈 Separator
+        0x111C9 => Some(ConsFinalModBelow), // 𑇉 Sandhi Mark
+        0x111CA => Some(ConsModBelow),      // 𑇊 Sign Nukta
+        0x111CB => Some(VowelAbove),        // 𑇋 Vowel Modifier Mark
+        0x111CC => Some(VowelBelow),        // 𑇌 Extra Short Vowel Mark
+        0x111CD => None,                    // 𑇍 Sutra Mark
+        0x111CE => Some(VowelPre),          // 𑇎 Sign Prishthamatra E
+        0x111CF => Some(VowelModAbove),     // 𑇏 Sign Inverted Candrabindu
+        0x111D0 => Some(Base),              // 𑇐 Digit Zero
+        0x111D1 => Some(Base),              // 𑇑 Digit One
+        0x111D2 => Some(Base),              // 𑇒 Digit Two
+        0x111D3 => Some(Base),              // 𑇓 Digit Three
+        0x111D4 => Some(Base),              // 𑇔 Digit Four
+        0x111D5 => Some(Base),              // 𑇕 Digit Five
+        0x111D6 => Some(Base),              // 𑇖 Digit Six
+        0x111D7 => Some(Base),              // 𑇗 Digit Seven
+        0x111D8 => Some(Base),              // 𑇘 Digit Eight
+        0x111D9 => Some(Base),              // 𑇙 Digit Nine
+        0x111DA => Some(Base),              // 𑇚 Ekam
+        0x111DB => None,                    // 𑇛 Sign Siddham
+        0x111DC => None,                    // 𑇜 Headstroke
+        0x111DD => None,                    // 𑇝 Continuation Sign
+        0x111DE => None,                    // 𑇞 Section Mark-1
+        0x111DF => None,                    // 𑇟 Section Mark-2
+
+        // Tirhuta character table
+        0x11480 => None,                // 𑒀 Anji
+        0x11481 => Some(Base),          // 𑒁 A
+        0x11482 => Some(Base),          // 𑒂 Aa
+        0x11483 => Some(Base),          // 𑒃 I
+        0x11484 => Some(Base),          // 𑒄 Ii
+        0x11485 => Some(Base),          // 𑒅 U
+        0x11486 => Some(Base),          // 𑒆 Uu
+        0x11487 => Some(Base),          // 𑒇 Vocalic R
+        0x11488 => Some(Base),          // 𑒈 Vocalic Rr
+        0x11489 => Some(Base),          // 𑒉 Vocalic L
+        0x1148A => Some(Base),          // 𑒊 Vocalic Ll
+        0x1148B => Some(Base),          // 𑒋 E
+        0x1148C => Some(Base),          // 𑒌 Ai
+        0x1148D => Some(Base),          // 𑒍 O
+        0x1148E => Some(Base),          // 𑒎 Au
+        0x1148F => Some(Base),          // 𑒏 Ka
+        0x11490 => Some(Base),          // 𑒐 Kha
+        0x11491 => Some(Base),          // 𑒑 Ga
+        0x11492 => Some(Base),          // 𑒒 Gha
+        0x11493 => Some(Base),          // 𑒓 Nga
+        0x11494 => Some(Base),          // 𑒔 Ca
+        0x11495 => Some(Base),          // 𑒕 Cha
+        0x11496 => Some(Base),          // 𑒖 Ja
+        0x11497 => Some(Base),          // 𑒗 Jha
+        0x11498 => Some(Base),          // 𑒘 Nya
+        0x11499 => Some(Base),          // 𑒙 Tta
+        0x1149A => Some(Base),          // 𑒚 Ttha
+        0x1149B => Some(Base),          // 𑒛 Dda
+        0x1149C => Some(Base),          // 𑒜 Ddha
+        0x1149D => Some(Base),          // 𑒝 Nna
+        0x1149E => Some(Base),          // 𑒞 Ta
+        0x1149F => Some(Base),          // 𑒟 Tha
+        0x114A0 => Some(Base),          // 𑒠 Da
+        0x114A1 => Some(Base),          // 𑒡 Dha
+        0x114A2 => Some(Base),          // 𑒢 Na
+        0x114A3 => Some(Base),          // 𑒣 Pa
+        0x114A4 => Some(Base),          // 𑒤 Pha
+        0x114A5 => Some(Base),          // 𑒥 Ba
+        0x114A6 => Some(Base),          // 𑒦 Bha
+        0x114A7 => Some(Base),          // 𑒧 Ma
+        0x114A8 => Some(Base),          // 𑒨 Ya
+        0x114A9 => Some(Base),          // 𑒩 Ra
+        0x114AA => Some(Base),          // 𑒪 La
+        0x114AB => Some(Base),          // 𑒫 Va
+        0x114AC => Some(Base),          // 𑒬 Sha
+        0x114AD => Some(Base),          // 𑒭 Ssa
+        0x114AE => Some(Base),          // 𑒮 Sa
+        0x114AF => Some(Base),          // 𑒯 Ha
+        0x114B0 => Some(VowelPost),     // 𑒰 Sign Aa
+        0x114B1 => Some(VowelPre),      // 𑒱 Sign I
+        0x114B2 => Some(VowelPost),     // 𑒲 Sign Ii
+        0x114B3 => Some(VowelBelow),    // 𑒳 Sign U
+        0x114B4 => Some(VowelBelow),    // 𑒴 Sign Uu
+        0x114B5 => Some(VowelBelow),    // 𑒵 Sign Vocalic R
+        0x114B6 => Some(VowelBelow),    // 𑒶 Sign Vocalic Rr
+        0x114B7 => Some(VowelBelow),    // 𑒷 Sign Vocalic L
+        0x114B8 => Some(VowelBelow),    // 𑒸 Sign Vocalic Ll
+        0x114B9 => Some(VowelPre),      // 𑒹 Sign E
+        0x114BA => Some(VowelAbove),    // 𑒺 Sign Short E
+        0x114BB => Some(VowelPre),      // 𑒻 Sign Ai
+        0x114BC => Some(VowelPre),      // 𑒼 Sign O
+        0x114BD => Some(VowelPost),     // 𑒽 Sign Short O
+        0x114BE => Some(VowelPre),      // 𑒾 Sign Au
+        0x114BF => Some(VowelModAbove), // 𑒿 Sign Candrabindu
+        0x114C0 => Some(VowelModAbove), // 𑓀 Sign Anusvara
+        0x114C1 => Some(VowelModAbove), // 𑓁 Sign Visarga
+        0x114C2 => Some(Halant),        // 𑓂 Sign Virama
+        0x114C3 => Some(ConsModBelow),  // 𑓃 Sign Nukta
+        0x114C4 => Some(Base),          // 𑓄 Sign Avagraha
+        0x114C5 => None,                // 𑓅 Gvang
+        0x114C6 => None,                // 𑓆 Abbreviation Sign
+        0x114C7 => None,                // 𑓇 Om
+        0x114D0 => Some(Base),          // 𑓐 Digit Zero
+        0x114D1 => Some(Base),          // 𑓑 Digit One
+        0x114D2 => Some(Base),          // 𑓒 Digit Two
+        0x114D3 => Some(Base),          // 𑓓 Digit Three
+        0x114D4 => Some(Base),          // 𑓔 Digit Four
+        0x114D5 => Some(Base),          // 𑓕 Digit Five
+        0x114D6 => Some(Base),          // 𑓖 Digit Six
+        0x114D7 => Some(Base),          // 𑓗 Digit Seven
+        0x114D8 => Some(Base),          // 𑓘 Digit Eight
+        0x114D9 => Some(Base),          // 𑓙 Digit Nine
+
+        // Kawi character table
+        0x11F00 => Some(VowelModAbove),    // 𑼀 Sign Candrabindu
+        0x11F01 => Some(VowelModAbove),    // 𑼁 Sign Anusvara
+        0x11F02 => Some(Repha),            // 𑼂 Sign Repha
+        0x11F03 => Some(VowelModPost),     // 𑼃 Sign Visarga
+        0x11F04 => Some(Base),             // 𑼄 A
+        0x11F05 => Some(Base),             // 𑼅 Aa
+        0x11F06 => Some(Base),             // 𑼆 I
+        0x11F07 => Some(Base),             // 𑼇 Ii
+        0x11F08 => Some(Base),             // 𑼈 U
+        0x11F09 => Some(Base),             // 𑼉 Uu
+        0x11F0A => Some(Base),             // 𑼊 Vocalic R
+        0x11F0B => Some(Base),             // 𑼋 Vocalic Rr
+        0x11F0C => Some(Base),             // 𑼌 Vocalic L
+        0x11F0D => Some(Base),             // 𑼍 Vocalic Ll
+        0x11F0E => Some(Base),             // 𑼎 E
+        0x11F0F => Some(Base),             // 𑼏 Ai
+        0x11F10 => Some(Base),             // 𑼐 O
+        0x11F12 => Some(Base),             // 𑼒 Ka
+        0x11F13 => Some(Base),             // 𑼓 Kha
+        0x11F14 => Some(Base),             // 𑼔 Ga
+        0x11F15 => Some(Base),             // 𑼕 Gha
+        0x11F16 => Some(Base),             // 𑼖 Nga
+        0x11F17 => Some(Base),             // 𑼗 Ca
+        0x11F18 => Some(Base),             // 𑼘 Cha
+        0x11F19 => Some(Base),             // 𑼙 Ja
+        0x11F1A => Some(Base),             // 𑼚 Jha
+        0x11F1B => Some(Base),             // 𑼛 Nya
+        0x11F1C => Some(Base),             // 𑼜 Tta
+        0x11F1D => Some(Base),             // 𑼝 Ttha
+        0x11F1E => Some(Base),             // 𑼞 Dda
+        0x11F1F => Some(Base),             // 𑼟 Ddha
+        0x11F20 => Some(Base),             // 𑼠 Nna
+        0x11F21 => Some(Base),             // 𑼡 Ta
+        0x11F22 => Some(Base),             // 𑼢 Tha
+        0x11F23 => Some(Base),             // 𑼣 Da
+        0x11F24 => Some(Base),             // 𑼤 Dha
+        0x11F25 => Some(Base),             // 𑼥 Na
+        0x11F26 => Some(Base),             // 𑼦 Pa
+        0x11F27 => Some(Base),             // 𑼧 Pha
+        0x11F28 => Some(Base),             // 𑼨 Ba
+        0x11F29 => Some(Base),             // 𑼩 Bha
+        0x11F2A => Some(Base),             // 𑼪 Ma
+        0x11F2B => Some(Base),             // 𑼫 Ya
+        0x11F2C => Some(Base),             // 𑼬 Ra
+        0x11F2D => Some(Base),             // 𑼭 La
+        0x11F2E => Some(Base),             // 𑼮 Wa
+        0x11F2F => Some(Base),             // 𑼯 Sha
+        0x11F30 => Some(Base),             // 𑼰 Ssa
+        0x11F31 => Some(Base),             // 𑼱 Sa
+        0x11F32 => Some(Base),             // 𑼲 Ha
+        0x11F33 => Some(Base),             // 𑼳 Jnya
+        0x11F34 => Some(VowelPost),        // 𑼴 Sign Aa
+        0x11F35 => Some(VowelPost),        // 𑼵 Sign Alternate Aa
+        0x11F36 => Some(VowelAbove),       // 𑼶 Sign I
+        0x11F37 => Some(VowelAbove),       // 𑼷 Sign Ii
+        0x11F38 => Some(VowelBelow),       // 𑼸 Sign U
+        0x11F39 => Some(VowelBelow),       // 𑼹 Sign Uu
+        0x11F3A => Some(VowelBelow),       // 𑼺 Sign Vocalic R
+        0x11F3E => Some(VowelPre),         // 𑼾 Sign E
+        0x11F3F => Some(VowelPre),         // 𑼿 Sign Ai
+        0x11F40 => Some(VowelAbove),       // 𑽀 Sign Eu
+        0x11F41 => Some(VowelPost),        // 𑽁 Sign Killer
+        0x11F42 => Some(InvisibleStacker), // 𑽂 Conjoiner
+        0x11F43 => None,                   // 𑽃 Danda
+        0x11F44 => None,                   // 𑽄 Double Danda
+        0x11F45 => None,                   // 𑽅 Punctuation Section Marker
+        0x11F46 => None,                   // 𑽆 Punctuation Alternate Section Marker
+        0x11F47 => None,                   // 𑽇 Punctuation Flower
+        0x11F48 => None,                   // 𑽈 Punctuation Space Filler
+        0x11F49 => None,                   // 𑽉 Punctuation Dot
+        0x11F4A => None,                   // 𑽊 Punctuation Double Dot
+        0x11F4B => None,                   // 𑽋 Punctuation Triple Dot
+        0x11F4C => None,                   // 𑽌 Punctuation Circle
+        0x11F4D => None,                   // 𑽍 Punctuation Filled Circle
+        0x11F4E => None,                   // 𑽎 Punctuation Spiral
+        0x11F4F => None,                   // 𑽏 Punctuation Closing Spiral
+        0x11F50 => Some(Base),             // 𑽐 Digit Zero
+        0x11F51 => Some(Base),             // 𑽑 Digit One
+        0x11F52 => Some(Base),             // 𑽒 Digit Two
+        0x11F53 => Some(Base),             // 𑽓 Digit Three
+        0x11F54 => Some(Base),             // 𑽔 Digit Four
+        0x11F55 => Some(Base),             // 𑽕 Digit Five
+        0x11F56 => Some(Base),             // 𑽖 Digit Six
+        0x11F57 => Some(Base),             // 𑽗 Digit Seven
+        0x11F58 => Some(Base),             // 𑽘 Digit Eight
+        0x11F59 => Some(Base),             // 𑽙 Digit Nine
+
+        // Miscellaneous character table
+        0x00A0 => Some(BaseOther),    //   No-break space
+        0x034F => Some(Cgj),          // ͏ Combining grapheme joiner
+        0x200C => Some(Zwnj),         // ‌ Zero-width non-joiner
+        0x200D => Some(Cgj),          // ‍ Zero-width joiner
+        0x2010 => Some(BaseOther),    // ‐ Hyphen
+        0x2011 => Some(BaseOther),    // ‑ No-break hyphen
+        0x2012 => Some(BaseOther),    // ‒ Figure dash
+        0x2013 => Some(BaseOther),    // – En dash
+        0x2014 => Some(BaseOther),    // — Em dash
+        0x2060 => Some(WordJoiner),   // ⁠ Word joiner
+        0x25CC => Some(Base),         // ◌ Dotted circle
+        0xFE00..=0xFE0F => Some(Cgj), // Variation selectors
+
+        _ => None,
+    }
+}
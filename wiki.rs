@@ -0,0 +1,374 @@
+//! Reading article text from MediaWiki XML dumps.
+
+use std::io::BufRead;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// Elements whose content is not running prose, such as references and formulas.
+const SKIPPED_TAGS: &[&str] = &[
+    "ref",
+    "math",
+    "chem",
+    "ce",
+    "gallery",
+    "imagemap",
+    "timeline",
+    "score",
+    "syntaxhighlight",
+    "source",
+    "templatedata",
+];
+
+/// Canonical namespace names and their aliases, which every wiki accepts whatever its
+/// language.
+const CANONICAL_NAMESPACES: &[&str] = &[
+    "media",
+    "special",
+    "talk",
+    "user",
+    "user talk",
+    "project",
+    "project talk",
+    "wikipedia",
+    "wikipedia talk",
+    "wp",
+    "file",
+    "file talk",
+    "image",
+    "image talk",
+    "mediawiki",
+    "mediawiki talk",
+    "template",
+    "template talk",
+    "help",
+    "help talk",
+    "category",
+    "category talk",
+    "portal",
+    "portal talk",
+    "draft",
+    "draft talk",
+    "module",
+    "module talk",
+    "timedtext",
+    "timedtext talk",
+];
+
+/// Interwiki prefixes of the sister projects
+const INTERWIKI_PREFIXES: &[&str] = &[
+    "b",
+    "c",
+    "commons",
+    "d",
+    "m",
+    "meta",
+    "mw",
+    "n",
+    "q",
+    "s",
+    "species",
+    "v",
+    "voy",
+    "w",
+    "wikibooks",
+    "wikidata",
+    "wikinews",
+    "wikiquote",
+    "wikisource",
+    "wikiversity",
+    "wikivoyage",
+    "wikt",
+    "wiktionary",
+];
+
+/// Iterates over the wikitext of the articles in a dump.
+///
+/// Pages outside the main namespace (talk pages, templates, categories and so on) and
+/// redirects are skipped.
+pub struct Articles<R: BufRead> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+    namespaces: Vec<String>,
+}
+
+impl<R: BufRead> Articles<R> {
+    pub fn new(input: R) -> Self {
+        Articles {
+            reader: Reader::from_reader(input),
+            buf: Vec::new(),
+            namespaces: Vec::new(),
+        }
+    }
+
+    /// The local names of the namespaces of the wiki, such as `श्रेणी` for categories on the
+    /// Hindi Wikipedia, as given before the first page of the dump.
+    pub fn namespaces(&self) -> &[String] {
+        &self.namespaces
+    }
+}
+
+enum Field {
+    Other,
+    Namespace,
+    NamespaceName,
+    Text,
+}
+
+impl<R: BufRead> Iterator for Articles<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let mut field = Field::Other;
+        let mut namespace = String::new();
+        let mut redirect = false;
+        let mut text = String::new();
+        loop {
+            self.buf.clear();
+            let event = match self.reader.read_event_into(&mut self.buf) {
+                Ok(event) => event,
+                Err(err) => {
+                    eprintln!("error in dump: {}", err);
+                    return None;
+                }
+            };
+            match event {
+                Event::Start(e) => match e.name().as_ref() {
+                    b"page" => {
+                        namespace.clear();
+                        redirect = false;
+                        text.clear();
+                    }
+                    b"ns" => field = Field::Namespace,
+                    b"namespace" => {
+                        self.namespaces.push(String::new());
+                        field = Field::NamespaceName;
+                    }
+                    b"text" => field = Field::Text,
+                    b"redirect" => redirect = true,
+                    _ => {}
+                },
+                Event::Empty(e) if e.name().as_ref() == b"redirect" => redirect = true,
+                Event::Text(e) => {
+                    // skip text with malformed entities rather than the whole dump
+                    if let Ok(s) = e.unescape() {
+                        match field {
+                            Field::Namespace => namespace.push_str(&s),
+                            Field::NamespaceName => {
+                                if let Some(name) = self.namespaces.last_mut() {
+                                    name.push_str(&s.to_lowercase());
+                                }
+                            }
+                            Field::Text => text.push_str(&s),
+                            Field::Other => {}
+                        }
+                    }
+                }
+                Event::End(e) => match e.name().as_ref() {
+                    b"page" if namespace == "0" && !redirect => return Some(text),
+                    b"ns" | b"namespace" | b"text" => field = Field::Other,
+                    _ => {}
+                },
+                Event::Eof => return None,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Strips wikitext markup, leaving the running prose of an article.
+///
+/// Comments, templates, tables, references and links to files and categories are removed
+/// entirely; other links are replaced by their label. `namespaces` are the local names of
+/// namespaces, in lower case, as read by [`Articles`].
+pub fn strip_markup(text: &str, namespaces: &[String]) -> String {
+    let mut out = String::with_capacity(text.len());
+    strip_into(text, namespaces, &mut out);
+    out
+}
+
+fn strip_into(text: &str, namespaces: &[String], out: &mut String) {
+    let mut rest = text;
+    while let Some(i) = rest.find(['{', '[', '<']) {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+        } else if rest.starts_with("{{") {
+            rest = &rest[nested_end(rest, "{{", "}}")..];
+        } else if rest.starts_with("{|") {
+            rest = &rest[table_end(rest)..];
+        } else if rest.starts_with("[[") {
+            let end = nested_end(rest, "[[", "]]");
+            let link = rest[2..end].trim_end_matches("]]");
+            strip_link(link, namespaces, out);
+            rest = &rest[end..];
+        } else if rest.starts_with("[http") || rest.starts_with("[//") {
+            // external link, keep the label
+            let end = rest.find(']').map_or(rest.len(), |end| end + 1);
+            let link = rest[1..end].trim_end_matches(']');
+            if let Some(space) = link.find(' ') {
+                strip_into(&link[space + 1..], namespaces, out);
+            }
+            rest = &rest[end..];
+        } else if rest.starts_with('<')
+            && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic() || c == '/')
+        {
+            rest = skip_tag(rest);
+        } else {
+            out.push_str(&rest[..1]);
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+}
+
+/// Writes the visible label of an internal link.
+///
+/// Links with a namespace or interwiki prefix (files, categories, other languages) have no
+/// visible text in the article, or only an image caption, and are dropped. Any other colon
+/// is part of the title, and a leading colon makes such a link visible.
+fn strip_link(link: &str, namespaces: &[String], out: &mut String) {
+    let target = link.split('|').next().unwrap_or("");
+    if let Some(colon) = target.find(':') {
+        if colon > 0 && is_prefix(&target[..colon], namespaces) {
+            return;
+        }
+    }
+    let label = match link.find('|') {
+        Some(bar) => &link[bar + 1..],
+        None => link.strip_prefix(':').unwrap_or(link),
+    };
+    strip_into(label, namespaces, out);
+}
+
+/// Whether the text before the colon of a link is a namespace, a sister project or a
+/// language code such as `en` or `zh-min-nan`.
+fn is_prefix(prefix: &str, namespaces: &[String]) -> bool {
+    let prefix = prefix.trim().replace('_', " ").to_lowercase();
+    let language = prefix.split('-').next().map_or(0, str::len);
+    CANONICAL_NAMESPACES.contains(&prefix.as_str())
+        || INTERWIKI_PREFIXES.contains(&prefix.as_str())
+        || namespaces.contains(&prefix)
+        || ((2..=3).contains(&language)
+            && prefix.chars().all(|c| c.is_ascii_lowercase() || c == '-'))
+}
+
+/// Returns the byte offset just past the `close` matching the `open` at the start of `s`,
+/// or the length of `s` if it is unbalanced.
+fn nested_end(s: &str, open: &str, close: &str) -> usize {
+    let mut depth = 0;
+    let mut i = 0;
+    while i < s.len() {
+        if s[i..].starts_with(open) {
+            depth += 1;
+            i += open.len();
+        } else if s[i..].starts_with(close) {
+            depth -= 1;
+            i += close.len();
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += s[i..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    s.len()
+}
+
+/// Returns the byte offset just past the end of the table at the start of `s`.
+///
+/// Tables open and close with `{|` and `|}` at the start of a line, and may be nested.
+fn table_end(s: &str) -> usize {
+    let mut depth = 0;
+    let mut offset = 0;
+    for line in s.split_inclusive('\n') {
+        offset += line.len();
+        let line = line.trim_start();
+        if line.starts_with("{|") {
+            depth += 1;
+        } else if line.starts_with("|}") {
+            depth -= 1;
+            if depth == 0 {
+                return offset;
+            }
+        }
+    }
+    s.len()
+}
+
+/// Skips an HTML tag, along with its content if it is one of the `SKIPPED_TAGS`.
+fn skip_tag(s: &str) -> &str {
+    let end = match s.find('>') {
+        Some(end) => end + 1,
+        None => return &s[1..], // a stray less-than sign
+    };
+    let tag = &s[1..end - 1];
+    let name: String = tag
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if tag.ends_with('/') || !SKIPPED_TAGS.contains(&name.as_str()) {
+        return &s[end..];
+    }
+    let mut rest = &s[end..];
+    while let Some(i) = rest.find("</") {
+        rest = &rest[i + 2..];
+        let closing = rest.as_bytes().get(..name.len() + 1);
+        if closing.is_some_and(|b| {
+            b[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+                && (b[name.len()] == b'>' || b[name.len()].is_ascii_whitespace())
+        }) {
+            return rest.find('>').map_or("", |end| &rest[end + 1..]);
+        }
+    }
+    ""
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strip(text: &str) -> String {
+        strip_markup(text, &["श्रेणी".to_string()])
+    }
+
+    #[test]
+    fn namespaces_are_read_from_the_dump() {
+        let dump = r#"<mediawiki><siteinfo><namespaces>
+            <namespace key="0" case="first-letter" />
+            <namespace key="14" case="first-letter">श्रेणी</namespace>
+            <namespace key="6" case="first-letter">File</namespace>
+            </namespaces></siteinfo>
+            <page><ns>0</ns><revision><text>[[श्रेणी:भारत]]</text></revision></page>
+            </mediawiki>"#;
+        let mut articles = Articles::new(dump.as_bytes());
+        assert_eq!(articles.next().as_deref(), Some("[[श्रेणी:भारत]]"));
+        assert_eq!(articles.namespaces(), ["श्रेणी", "file"]);
+    }
+
+    #[test]
+    fn titles_with_colons_are_kept() {
+        assert_eq!(
+            strip("[[Star Wars: A New Hope]] x"),
+            "Star Wars: A New Hope x"
+        );
+        assert_eq!(strip("[[Star Wars: A New Hope|film]] x"), "film x");
+    }
+
+    #[test]
+    fn leading_colon_links_are_kept() {
+        assert_eq!(strip("see [[:Category:X|label]]"), "see label");
+        assert_eq!(strip("see [[:श्रेणी:भारत]]"), "see श्रेणी:भारत");
+    }
+
+    #[test]
+    fn namespace_and_language_links_are_dropped() {
+        assert_eq!(strip("a[[Category:X]]b"), "ab");
+        assert_eq!(strip("a[[file:X.jpg|thumb|caption]]b"), "ab");
+        assert_eq!(strip("a[[श्रेणी:भारत]]b"), "ab");
+        assert_eq!(strip("a[[en:India]][[zh-min-nan:India]]b"), "ab");
+        assert_eq!(strip("a[[wikt:कर]]b"), "ab");
+    }
+}
@@ -6,6 +6,9 @@ base=`dirname $0`
 date=20190801
 my_date=20240601 # Myanmar
 
+# extra options for corpus, such as max-words=10000000 to limit memory use
+corpus_options=
+
 mkdir -p $base/$date $base/$my_date
 
 function download() {
@@ -20,7 +23,7 @@ function build-corpus() {
     local date=$2
 
     echo $i
-    bzip2 -dc $base/$date/${i}wiki-$date-pages-articles-multistream.xml.bz2 | $base/../target/release/corpus $i html > $base/../words/$i.wiki.$date
+    $base/../target/release/corpus $i wiki $corpus_options < $base/$date/${i}wiki-$date-pages-articles-multistream.xml.bz2 > $base/../words/$i.wiki.$date
 }
 
 for i in bn hi ta or te gu pa ml kn si my ; do
@@ -0,0 +1,258 @@
+//! Telling Zawgyi text from Unicode Myanmar text, and converting it to Unicode.
+//!
+//! Zawgyi is a font encoding that reuses the Myanmar block. It stores text in visual order,
+//! with the vowel sign E and medial ra before the consonant, and has its own code points for
+//! stacked consonants and for the alternate shapes of some marks. Much Burmese text online
+//! is still in Zawgyi, and the Unicode grammar either rejects it or finds nonsense
+//! syllables in it.
+
+use super::{segment, Script};
+
+/// How much a line of text looks like Zawgyi rather than Unicode.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ZawgyiScore {
+    /// Myanmar characters that the Unicode grammar cannot attach to a syllable
+    pub broken_as_unicode: usize,
+    /// The same, after converting the text from Zawgyi
+    pub broken_as_zawgyi: usize,
+    /// Characters and sequences only found in Zawgyi text
+    pub zawgyi: usize,
+    /// Characters and sequences only found in Unicode text
+    pub unicode: usize,
+}
+
+impl ZawgyiScore {
+    /// Positive for Zawgyi, negative for Unicode and 0 when there is nothing to tell them
+    /// apart.
+    pub fn score(&self) -> isize {
+        (self.broken_as_unicode + self.zawgyi) as isize
+            - (self.broken_as_zawgyi + self.unicode) as isize
+    }
+
+    pub fn is_zawgyi(&self) -> bool {
+        self.score() > 0
+    }
+}
+
+/// Scores `text` by how well it parses under the Unicode grammar, as it is and converted
+/// from Zawgyi, and by the sequences that only one of the encodings uses.
+pub fn zawgyi_score(text: &str) -> ZawgyiScore {
+    let mut score = ZawgyiScore {
+        broken_as_unicode: broken_chars(text),
+        broken_as_zawgyi: broken_chars(&zawgyi_to_unicode(text)),
+        ..ZawgyiScore::default()
+    };
+    let chars: Vec<char> = text.chars().collect();
+    for (i, &ch) in chars.iter().enumerate() {
+        let previous = if i > 0 { Some(chars[i - 1]) } else { None };
+        let next = chars.get(i + 1).copied();
+        match ch {
+            // Mon, Karen and Shan letters in Unicode, which Burmese does not use
+            '\u{1033}' | '\u{1034}' | '\u{105A}' | '\u{1060}'..='\u{1097}' => score.zawgyi += 1,
+            // the vowel sign E and medial ra at the start of a word
+            '\u{1031}' | '\u{103B}' if !previous.is_some_and(myanmar_char) => score.zawgyi += 1,
+            // the Zawgyi asat, where a stacker would need a consonant after it
+            '\u{1039}' if !next.is_some_and(|c| ('\u{1000}'..='\u{1021}').contains(&c)) => {
+                score.zawgyi += 1
+            }
+            // medial ha and great sa, which Zawgyi does not have
+            '\u{103E}' | '\u{103F}' => score.unicode += 1,
+            // kinzi, which is a single character in Zawgyi
+            '\u{1004}' if next == Some('\u{103A}') && chars.get(i + 2) == Some(&'\u{1039}') => {
+                score.unicode += 1
+            }
+            // an asat before visarga, where Zawgyi would have medial ya without a vowel
+            '\u{103A}' if next == Some('\u{1038}') => score.unicode += 1,
+            // the vowel sign E after a medial, where Zawgyi puts it before the consonant
+            '\u{1031}' if previous.is_some_and(|c| ('\u{103B}'..='\u{103E}').contains(&c)) => {
+                score.unicode += 1
+            }
+            _ => {}
+        }
+    }
+    score
+}
+
+fn myanmar_char(ch: char) -> bool {
+    ('\u{1000}'..='\u{109F}').contains(&ch)
+}
+
+fn broken_chars(text: &str) -> usize {
+    segment(text, Script::Myanmar)
+        .filter(|cluster| cluster.broken && cluster.text.chars().any(myanmar_char))
+        .count()
+}
+
+/// A character of Zawgyi text, as the Unicode characters it stands for.
+enum Part {
+    /// The vowel sign E or medial ra, which Zawgyi puts before the consonant
+    Prefix(char),
+    /// A consonant or independent vowel, with any consonant stacked under it
+    Base(char, Option<char>),
+    /// A consonant stacked under the one before it
+    Stacked(char),
+    /// A kinzi, which Zawgyi puts after the consonant, with any mark drawn together with it
+    Kinzi(Option<char>),
+    Mark(char),
+    Marks(&'static [char]),
+    Other,
+}
+
+fn part(ch: char) -> Part {
+    match ch {
+        '\u{1031}' => Part::Prefix(ch),
+        '\u{103B}' | '\u{107E}'..='\u{1084}' => Part::Prefix('\u{103C}'),
+        '\u{1000}'..='\u{102A}' | '\u{104C}'..='\u{104F}' => Part::Base(ch, None),
+        '\u{106A}' => Part::Base('\u{1009}', None),
+        '\u{106B}' => Part::Base('\u{100A}', None),
+        '\u{1086}' => Part::Base('\u{103F}', None),
+        '\u{108F}' => Part::Base('\u{1014}', None),
+        '\u{1090}' => Part::Base('\u{101B}', None),
+        '\u{106E}' => Part::Base('\u{100D}', Some('\u{100D}')),
+        '\u{106F}' => Part::Base('\u{100D}', Some('\u{100E}')),
+        '\u{1091}' => Part::Base('\u{100F}', Some('\u{100D}')),
+        '\u{1092}' => Part::Base('\u{100B}', Some('\u{100C}')),
+        '\u{1097}' => Part::Base('\u{100B}', Some('\u{100B}')),
+        '\u{1060}' => Part::Stacked('\u{1000}'),
+        '\u{1061}' => Part::Stacked('\u{1001}'),
+        '\u{1062}' => Part::Stacked('\u{1002}'),
+        '\u{1063}' => Part::Stacked('\u{1003}'),
+        '\u{1065}' => Part::Stacked('\u{1005}'),
+        '\u{1066}' | '\u{1067}' => Part::Stacked('\u{1006}'),
+        '\u{1068}' => Part::Stacked('\u{1007}'),
+        '\u{1069}' => Part::Stacked('\u{1008}'),
+        '\u{106C}' => Part::Stacked('\u{100B}'),
+        '\u{106D}' => Part::Stacked('\u{100C}'),
+        '\u{1070}' => Part::Stacked('\u{100F}'),
+        '\u{1071}' | '\u{1072}' => Part::Stacked('\u{1010}'),
+        '\u{1073}' | '\u{1074}' => Part::Stacked('\u{1011}'),
+        '\u{1075}' => Part::Stacked('\u{1012}'),
+        '\u{1076}' => Part::Stacked('\u{1013}'),
+        '\u{1077}' => Part::Stacked('\u{1014}'),
+        '\u{1078}' => Part::Stacked('\u{1015}'),
+        '\u{1079}' => Part::Stacked('\u{1016}'),
+        '\u{107A}' => Part::Stacked('\u{1017}'),
+        '\u{107B}' | '\u{1093}' => Part::Stacked('\u{1018}'),
+        '\u{107C}' => Part::Stacked('\u{1019}'),
+        '\u{1085}' => Part::Stacked('\u{101C}'),
+        '\u{1064}' => Part::Kinzi(None),
+        '\u{108B}' => Part::Kinzi(Some('\u{102D}')),
+        '\u{108C}' => Part::Kinzi(Some('\u{102E}')),
+        '\u{108D}' => Part::Kinzi(Some('\u{1036}')),
+        // Zawgyi has the medials one code point lower, and its virama is drawn as an asat
+        '\u{1039}' => Part::Mark('\u{103A}'),
+        '\u{103A}' => Part::Mark('\u{103B}'),
+        '\u{103C}' => Part::Mark('\u{103D}'),
+        '\u{103D}' | '\u{1087}' => Part::Mark('\u{103E}'),
+        '\u{1088}' => Part::Marks(&['\u{103E}', '\u{102F}']),
+        '\u{1089}' => Part::Marks(&['\u{103E}', '\u{1030}']),
+        '\u{108A}' => Part::Marks(&['\u{103D}', '\u{103E}']),
+        '\u{1033}' => Part::Mark('\u{102F}'),
+        '\u{1034}' => Part::Mark('\u{1030}'),
+        '\u{105A}' => Part::Marks(&['\u{102B}', '\u{103A}']),
+        '\u{1094}' | '\u{1095}' => Part::Mark('\u{1037}'),
+        '\u{102B}'..='\u{1030}' | '\u{1032}'..='\u{1038}' => Part::Mark(ch),
+        _ => Part::Other,
+    }
+}
+
+/// Where a mark goes after the base in Unicode, which stores stacked consonants first, then
+/// the medials, the vowel signs, anusvara, dot below, asat and visarga.
+///
+/// The dot below comes before the asat, which is the canonical order.
+fn mark_order(ch: char) -> u8 {
+    match ch {
+        '\u{103B}' => 1,
+        '\u{103C}' => 2,
+        '\u{103D}' => 3,
+        '\u{103E}' => 4,
+        '\u{1031}' => 5,
+        '\u{102D}' | '\u{102E}' | '\u{1032}' => 6,
+        '\u{102F}' | '\u{1030}' => 7,
+        '\u{102B}' | '\u{102C}' => 8,
+        '\u{1036}' => 9,
+        '\u{1037}' => 10,
+        '\u{103A}' => 11,
+        '\u{1038}' => 12,
+        _ => 13,
+    }
+}
+
+/// A syllable of Zawgyi text, in Unicode characters but not yet in Unicode order.
+#[derive(Default)]
+struct Pending {
+    kinzi: bool,
+    base: Option<char>,
+    /// The marks with their place in the Unicode order, 0 for stacked consonants
+    marks: Vec<(u8, char)>,
+}
+
+impl Pending {
+    fn mark(&mut self, ch: char) {
+        self.marks.push((mark_order(ch), ch));
+    }
+
+    fn stack(&mut self, ch: char) {
+        self.marks.push((0, '\u{1039}'));
+        self.marks.push((0, ch));
+    }
+
+    fn flush(&mut self, out: &mut String) {
+        if self.kinzi {
+            out.push_str("\u{1004}\u{103A}\u{1039}");
+        }
+        out.extend(self.base);
+        // a stable sort, so a stacker stays with its consonant
+        self.marks.sort_by_key(|&(order, _)| order);
+        out.extend(self.marks.iter().map(|&(_, ch)| ch));
+        *self = Pending::default();
+    }
+}
+
+/// Converts Zawgyi text to Unicode.
+///
+/// The characters of each syllable are mapped to Unicode and put in Unicode order, with the
+/// kinzi before the consonant and the vowel sign E and medial ra after it. Everything
+/// outside the Myanmar block is left alone.
+pub fn zawgyi_to_unicode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut pending = Pending::default();
+    for ch in text.chars() {
+        match part(ch) {
+            Part::Prefix(c) => {
+                if pending.base.is_some() {
+                    pending.flush(&mut out);
+                }
+                pending.mark(c);
+            }
+            Part::Base(c, stacked) => {
+                if pending.base.is_some() {
+                    pending.flush(&mut out);
+                }
+                pending.base = Some(c);
+                if let Some(stacked) = stacked {
+                    pending.stack(stacked);
+                }
+            }
+            Part::Stacked(c) => pending.stack(c),
+            Part::Kinzi(mark) => {
+                pending.kinzi = true;
+                if let Some(mark) = mark {
+                    pending.mark(mark);
+                }
+            }
+            Part::Mark(c) => pending.mark(c),
+            Part::Marks(cs) => {
+                for &c in cs {
+                    pending.mark(c);
+                }
+            }
+            Part::Other => {
+                pending.flush(&mut out);
+                out.push(ch);
+            }
+        }
+    }
+    pending.flush(&mut out);
+    out
+}